@@ -0,0 +1,164 @@
+use crate::advanced::{
+    Matrix,
+    MatrixRow,
+    apply_transformation,
+    invert_matrix,
+    multiply_matrices,
+};
+
+/// A chromatic adaptation transform, which predicts how a colour seen under
+/// one white point appears under another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, as used by ICC profiles.
+    Bradford,
+    /// The von Kries transform, using the Hunt-Pointer-Estevez cone
+    /// responses.
+    VonKries,
+    /// The CIECAM02 transform.
+    Cat02,
+    /// Scaling XYZ directly, without a cone response domain.
+    XyzScaling,
+}
+
+impl ChromaticAdaptation {
+    /// The matrix converting XYZ into the cone response domain of the
+    /// transform.
+    #[inline]
+    #[must_use]
+    pub const fn cone_response_matrix(self) -> Matrix {
+        match self {
+            Self::Bradford => {
+                [
+                    [0.895_1, 0.266_4, -0.161_4],
+                    [-0.750_2, 1.713_5, 0.036_7],
+                    [0.038_9, -0.068_5, 1.029_6],
+                ]
+            },
+            Self::VonKries => {
+                [
+                    [0.400_24, 0.707_60, -0.080_81],
+                    [-0.226_30, 1.165_32, 0.045_70],
+                    [0., 0., 0.918_22],
+                ]
+            },
+            Self::Cat02 => {
+                [
+                    [0.732_8, 0.429_6, -0.162_4],
+                    [-0.703_6, 1.697_5, 0.006_1],
+                    [0.003_0, 0.013_6, 0.983_4],
+                ]
+            },
+            Self::XyzScaling => [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+        }
+    }
+
+    /// Calculates the matrix adapting XYZ values from the `source` white point
+    /// to the `destination` white point.
+    #[must_use]
+    pub fn adaptation_matrix(self, source: &MatrixRow, destination: &MatrixRow) -> Matrix {
+        let cone_response = self.cone_response_matrix();
+
+        let source_response = apply_transformation(source, &cone_response);
+        let destination_response = apply_transformation(destination, &cone_response);
+
+        let mut scale = [[0.; 3]; 3];
+
+        for (i, row) in scale.iter_mut().enumerate() {
+            row[i] = destination_response[i] / source_response[i];
+        }
+
+        multiply_matrices(
+            &invert_matrix(&cone_response),
+            &multiply_matrices(&scale, &cone_response),
+        )
+    }
+
+    /// Adapts XYZ values from the `source` white point to the `destination`
+    /// white point.
+    #[allow(clippy::float_cmp)]
+    #[must_use]
+    pub fn adapt(self, xyz: &MatrixRow, source: &MatrixRow, destination: &MatrixRow) -> MatrixRow {
+        // Exact equality is intended, as identical white points are the common
+        // case and need no adaptation.
+        if source == destination {
+            return *xyz;
+        }
+
+        apply_transformation(xyz, &self.adaptation_matrix(source, destination))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::ChromaticAdaptation,
+        crate::{
+            advanced::{
+                A_STANDARD_ILLUMINANT,
+                D50_STANDARD_ILLUMINANT,
+                D65_STANDARD_ILLUMINANT,
+                F11_STANDARD_ILLUMINANT,
+            },
+            models::color_model::test_utils::assert_close,
+        },
+    };
+
+    #[test]
+    fn test_bradford_d65_to_d50() {
+        let matrix = ChromaticAdaptation::Bradford
+            .adaptation_matrix(&D65_STANDARD_ILLUMINANT, &D50_STANDARD_ILLUMINANT);
+
+        for (actual, expected) in matrix.iter().zip([
+            [1.047_811_2, 0.022_886_6, -0.050_127_0],
+            [0.029_542_4, 0.990_484_4, -0.017_049_1],
+            [-0.009_234_5, 0.015_043_6, 0.752_131_6],
+        ]) {
+            for (actual, expected) in actual.iter().zip(expected) {
+                assert_close(*actual, expected, 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_white_point_maps_to_white_point() {
+        for method in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::XyzScaling,
+        ] {
+            let adapted = method.adapt(
+                &A_STANDARD_ILLUMINANT,
+                &A_STANDARD_ILLUMINANT,
+                &F11_STANDARD_ILLUMINANT,
+            );
+
+            for (actual, expected) in adapted.iter().zip(F11_STANDARD_ILLUMINANT) {
+                assert_close(*actual, expected, 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let xyz = [0.2, 0.3, 0.4];
+
+        for method in [
+            ChromaticAdaptation::Bradford,
+            ChromaticAdaptation::VonKries,
+            ChromaticAdaptation::Cat02,
+            ChromaticAdaptation::XyzScaling,
+        ] {
+            let round_trip = method.adapt(
+                &method.adapt(&xyz, &D65_STANDARD_ILLUMINANT, &D50_STANDARD_ILLUMINANT),
+                &D50_STANDARD_ILLUMINANT,
+                &D65_STANDARD_ILLUMINANT,
+            );
+
+            for (actual, expected) in round_trip.iter().zip(xyz) {
+                assert_close(*actual, expected, 1e-5);
+            }
+        }
+    }
+}
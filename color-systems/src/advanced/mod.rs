@@ -1,70 +1,146 @@
+//! Colour science building blocks: matrices, standard illuminants, RGB colour
+//! spaces, transfer functions and chromatic adaptation.
+
+/// Converting XYZ colours between reference whites.
+mod chromatic_adaptation;
+/// Encoding and decoding the non-linear components of RGB colour spaces.
+mod transfer_function;
+
 use std::mem::MaybeUninit;
 
+pub use {
+    chromatic_adaptation::ChromaticAdaptation,
+    transfer_function::TransferFunction,
+};
+
+/// A row of three components, such as an XYZ or RGB triple.
 pub type MatrixRow = [f64; 3];
+/// A 3×3 matrix, stored as rows.
 pub type Matrix = [MatrixRow; 3];
 
-fn apply_transformation(to: &MatrixRow, using: &Matrix) -> MatrixRow {
+/// Multiplies the row vector `to` by the matrix `using`.
+pub(crate) fn apply_transformation(to: &MatrixRow, using: &Matrix) -> MatrixRow {
     [
-        using[0][0] * to[0] + using[0][1] * to[1] + using[0][2] * to[2],
-        using[1][0] * to[0] + using[1][1] * to[1] + using[1][2] * to[2],
-        using[2][0] * to[0] + using[2][1] * to[1] + using[2][2] * to[2],
+        using[0][2].mul_add(to[2], using[0][0].mul_add(to[0], using[0][1] * to[1])),
+        using[1][2].mul_add(to[2], using[1][0].mul_add(to[0], using[1][1] * to[1])),
+        using[2][2].mul_add(to[2], using[2][0].mul_add(to[0], using[2][1] * to[1])),
     ]
 }
 
+/// Multiplies two 3×3 matrices.
+fn multiply_matrices(left: &Matrix, right: &Matrix) -> Matrix {
+    [0, 1, 2].map(|row| {
+        [0, 1, 2].map(|column| {
+            left[row][0].mul_add(
+                right[0][column],
+                left[row][1].mul_add(right[1][column], left[row][2] * right[2][column]),
+            )
+        })
+    })
+}
+
+/// Inverts a 3×3 matrix.
 fn invert_matrix(input: &Matrix) -> Matrix {
-    let atom_1 = input[1][1] * input[2][2] - input[1][2] * input[2][1];
+    let atom_1 = input[1][1].mul_add(input[2][2], -(input[1][2] * input[2][1]));
     let atom_2 = input[1][0] * input[2][2];
     let atom_3 = input[1][2] * input[2][0];
-    let atom_4 = input[1][0] * input[2][1] - input[1][1] * input[2][0];
+    let atom_4 = input[1][0].mul_add(input[2][1], -(input[1][1] * input[2][0]));
 
-    let determinant = input[0][0] * atom_1 - input[0][1] * (atom_2 - atom_3) + input[0][2] * atom_4;
+    let determinant = input[0][2].mul_add(
+        atom_4,
+        input[0][0].mul_add(atom_1, -(input[0][1] * (atom_2 - atom_3))),
+    );
 
     [
         [
             atom_1 / determinant,
-            (input[0][2] * input[2][1] - input[0][1] * input[2][2]) / determinant,
-            (input[0][1] * input[1][2] - input[0][2] * input[1][1]) / determinant,
+            input[0][2].mul_add(input[2][1], -(input[0][1] * input[2][2])) / determinant,
+            input[0][1].mul_add(input[1][2], -(input[0][2] * input[1][1])) / determinant,
         ],
         [
             (atom_3 - atom_2) / determinant,
-            (input[0][0] * input[2][2] - input[0][2] * input[2][0]) / determinant,
-            (input[0][2] * input[1][0] - input[0][0] * input[1][2]) / determinant,
+            input[0][0].mul_add(input[2][2], -(input[0][2] * input[2][0])) / determinant,
+            input[0][2].mul_add(input[1][0], -(input[0][0] * input[1][2])) / determinant,
         ],
         [
             atom_4 / determinant,
-            (input[0][1] * input[2][0] - input[0][0] * input[2][1]) / determinant,
-            (input[0][0] * input[1][1] - input[0][1] * input[1][0]) / determinant,
+            input[0][1].mul_add(input[2][0], -(input[0][0] * input[2][1])) / determinant,
+            input[0][0].mul_add(input[1][1], -(input[0][1] * input[1][0])) / determinant,
         ],
     ]
 }
 
-pub const D65_STANDARD_ILLUMINANT: MatrixRow = [96.047, 100., 108.833];
+/// CIE standard illuminant A (incandescent tungsten).
+pub const A_STANDARD_ILLUMINANT: MatrixRow = [109.850, 100., 35.585];
+/// CIE standard illuminant C (average daylight, superseded by D65).
+pub const C_STANDARD_ILLUMINANT: MatrixRow = [98.074, 100., 118.232];
+/// CIE standard illuminant D50 (horizon light), the ICC profile connection
+/// space white point.
+pub const D50_STANDARD_ILLUMINANT: MatrixRow = [96.422, 100., 82.521];
+/// CIE standard illuminant D55 (mid-morning daylight).
+pub const D55_STANDARD_ILLUMINANT: MatrixRow = [95.682, 100., 92.149];
+/// CIE standard illuminant D65 (noon daylight), the white point of sRGB.
+pub const D65_STANDARD_ILLUMINANT: MatrixRow = [95.047, 100., 108.883];
+/// CIE standard illuminant D75 (north sky daylight).
+pub const D75_STANDARD_ILLUMINANT: MatrixRow = [94.972, 100., 122.638];
+/// CIE standard illuminant E (equal energy).
+pub const E_STANDARD_ILLUMINANT: MatrixRow = [100., 100., 100.];
+/// CIE standard illuminant F2 (cool white fluorescent).
+pub const F2_STANDARD_ILLUMINANT: MatrixRow = [99.187, 100., 67.395];
+/// CIE standard illuminant F7 (broadband daylight fluorescent).
+pub const F7_STANDARD_ILLUMINANT: MatrixRow = [95.044, 100., 108.755];
+/// CIE standard illuminant F11 (narrowband white fluorescent).
+pub const F11_STANDARD_ILLUMINANT: MatrixRow = [100.966, 100., 64.370];
 
+/// Linear sRGB to XYZ, from IEC 61966-2-1.
 const SRGB_TO_XYZ_MATRIX: Matrix = [
-    [0.4124564, 0.3575761, 0.1804375],
-    [0.2126729, 0.7151522, 0.0721750],
-    [0.0193339, 0.1191920, 0.9503041],
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175_0],
+    [0.019_333_9, 0.119_192_0, 0.950_304_1],
 ];
 
+/// XYZ to linear sRGB, from IEC 61966-2-1.
 const XYZ_TO_SRGB_MATRIX: Matrix = [
-    [3.2404542, -1.5371385, -0.4985314],
-    [-0.9692660, 1.8760108, 0.0415560],
-    [0.0556434, -0.2040259, 1.0572252],
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266_0, 1.876_010_8, 0.041_556_0],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
 ];
 
+/// Converts an xy chromaticity coordinate into XYZ, scaled so that `Y` is
+/// `100` like [`D65_STANDARD_ILLUMINANT`].
+#[inline]
+#[must_use]
+pub const fn chromaticity_to_xyz(x: f64, y: f64) -> MatrixRow {
+    [x / y * 100., 100., (1. - x - y) / y * 100.]
+}
+
+/// An RGB colour space, defined by the chromaticities of its primaries, its
+/// white point and its transfer function.
 pub struct RgbColourSpace {
-    x_r:         f64,
-    y_r:         f64,
-    x_g:         f64,
-    y_g:         f64,
-    x_b:         f64,
-    y_b:         f64,
+    /// Chromaticity `x` of the red primary.
+    x_r: f64,
+    /// Chromaticity `y` of the red primary.
+    y_r: f64,
+    /// Chromaticity `x` of the green primary.
+    x_g: f64,
+    /// Chromaticity `y` of the green primary.
+    y_g: f64,
+    /// Chromaticity `x` of the blue primary.
+    x_b: f64,
+    /// Chromaticity `y` of the blue primary.
+    y_b: f64,
+    /// XYZ of the white point.
     white_point: MatrixRow,
+    /// Encodes and decodes the components.
+    transfer_function: TransferFunction,
 }
 
+/// Zips two arrays of the same length into an array of pairs.
 trait StaticZippable<E, Z, const N: usize> {
+    /// The array of pairs.
     type ZipType;
 
+    /// Pairs up the elements of `self` and `other`.
     fn zip(self, other: Z) -> Self::ZipType;
 }
 
@@ -78,7 +154,10 @@ impl<E, F, const N: usize> StaticZippable<E, [F; N], N> for [E; N] {
         let mut other_iter = other.into_iter();
 
         for elem in &mut data {
-            elem.write((self_iter.next().unwrap(), other_iter.next().unwrap()));
+            elem.write((
+                self_iter.next().expect("Arrays are the same length"),
+                other_iter.next().expect("Arrays are the same length"),
+            ));
         }
 
         data.map(|elem| unsafe { elem.assume_init() })
@@ -100,6 +179,102 @@ impl<E: Copy, F: Copy, const N: usize> StaticZippable<E, &[F; N], N> for [E; N]
 }
 
 impl RgbColourSpace {
+    /// ACEScg (ACES AP1 primaries, ACES white point, linear).
+    pub const ACES_CG: Self = Self::new(
+        (0.713, 0.293),
+        (0.165, 0.830),
+        (0.128, 0.044),
+        chromaticity_to_xyz(0.321_68, 0.337_67),
+        TransferFunction::Linear,
+    );
+    /// Adobe RGB (1998).
+    pub const ADOBE_RGB: Self = Self::new(
+        (0.64, 0.33),
+        (0.21, 0.71),
+        (0.15, 0.06),
+        D65_STANDARD_ILLUMINANT,
+        TransferFunction::Gamma(563. / 256.),
+    );
+    /// Display P3 (DCI-P3 primaries, D65 white point, sRGB transfer function).
+    pub const DISPLAY_P3: Self = Self::new(
+        (0.680, 0.320),
+        (0.265, 0.690),
+        (0.150, 0.060),
+        D65_STANDARD_ILLUMINANT,
+        TransferFunction::Srgb,
+    );
+    /// ProPhoto RGB, also known as ROMM RGB, which uses a D50 white point.
+    pub const PROPHOTO_RGB: Self = Self::new(
+        (0.734_7, 0.265_3),
+        (0.159_6, 0.840_4),
+        (0.036_6, 0.000_1),
+        D50_STANDARD_ILLUMINANT,
+        TransferFunction::RommRgb,
+    );
+    /// ITU-R BT.2020.
+    pub const REC_2020: Self = Self::new(
+        (0.708, 0.292),
+        (0.170, 0.797),
+        (0.131, 0.046),
+        D65_STANDARD_ILLUMINANT,
+        TransferFunction::Rec709,
+    );
+    /// ITU-R BT.709, which shares its primaries with sRGB.
+    pub const REC_709: Self = Self::new(
+        (0.64, 0.33),
+        (0.30, 0.60),
+        (0.15, 0.06),
+        D65_STANDARD_ILLUMINANT,
+        TransferFunction::Rec709,
+    );
+    /// sRGB (IEC 61966-2-1).
+    pub const SRGB: Self = Self::new(
+        (0.64, 0.33),
+        (0.30, 0.60),
+        (0.15, 0.06),
+        D65_STANDARD_ILLUMINANT,
+        TransferFunction::Srgb,
+    );
+
+    /// Defines a colour space from the xy chromaticities of its red, green and
+    /// blue primaries, the XYZ of its white point and its transfer function.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        (x_r, y_r): (f64, f64),
+        (x_g, y_g): (f64, f64),
+        (x_b, y_b): (f64, f64),
+        white_point: MatrixRow,
+        transfer_function: TransferFunction,
+    ) -> Self {
+        Self {
+            x_r,
+            y_r,
+            x_g,
+            y_g,
+            x_b,
+            y_b,
+            white_point,
+            transfer_function,
+        }
+    }
+
+    /// The XYZ of the white point, scaled so that `Y` is `100`.
+    #[inline]
+    #[must_use]
+    pub const fn white_point(&self) -> MatrixRow {
+        self.white_point
+    }
+
+    /// The function that encodes linear light into the colour space's
+    /// components.
+    #[inline]
+    #[must_use]
+    pub const fn transfer_function(&self) -> TransferFunction {
+        self.transfer_function
+    }
+
+    /// Derives the linear RGB to XYZ matrix from the primaries and white point.
     fn get_xyz_transform_matrix(&self) -> Matrix {
         let rgb_matrix = [
             [
@@ -115,28 +290,129 @@ impl RgbColourSpace {
             ],
         ];
 
-        let s_vector = apply_transformation(&self.white_point, &invert_matrix(&rgb_matrix));
+        let white_point = self.white_point.map(|c| c / self.white_point[1]);
+        let s_vector = apply_transformation(&white_point, &invert_matrix(&rgb_matrix));
 
         rgb_matrix.map(|row| row.zip(s_vector).map(|(r, s)| r * s))
     }
 }
 
+/// Converts between linear-light RGB in a colour space and CIE XYZ.
 pub struct RgbXyzConverter {
+    /// Linear RGB to XYZ.
     rgb_to_xyz: Matrix,
+    /// XYZ to linear RGB.
     xyz_to_rgb: Matrix,
 }
 
 impl RgbXyzConverter {
+    /// The converter for sRGB, using the matrices from the sRGB specification.
     pub const SRGB_CONVERTER: Self = Self::define_matrices(SRGB_TO_XYZ_MATRIX, XYZ_TO_SRGB_MATRIX);
 
-    pub fn calculate_matrices(colour_space: ()) -> Self {
-        todo!()
+    /// Calculates the conversion matrices for a colour space from its
+    /// primaries and white point.
+    #[must_use]
+    pub fn calculate_matrices(colour_space: &RgbColourSpace) -> Self {
+        let rgb_to_xyz = colour_space.get_xyz_transform_matrix();
+
+        Self::define_matrices(rgb_to_xyz, invert_matrix(&rgb_to_xyz))
     }
 
+    /// Creates a converter from precalculated matrices.
+    #[must_use]
     pub const fn define_matrices(rgb_to_xyz: Matrix, xyz_to_rgb: Matrix) -> Self {
         Self {
             rgb_to_xyz,
             xyz_to_rgb,
         }
     }
+
+    /// Converts linear-light RGB components into CIE XYZ, where the
+    /// reference white has a luminance (`Y`) of `1`.
+    #[inline]
+    #[must_use]
+    pub fn rgb_to_xyz(&self, rgb: &MatrixRow) -> MatrixRow {
+        apply_transformation(rgb, &self.rgb_to_xyz)
+    }
+
+    /// Converts CIE XYZ into linear-light RGB components. The result is not
+    /// clamped, so colours outside of the RGB gamut produce components outside
+    /// of `0..=1`.
+    #[inline]
+    #[must_use]
+    pub fn xyz_to_rgb(&self, xyz: &MatrixRow) -> MatrixRow {
+        apply_transformation(xyz, &self.xyz_to_rgb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            Matrix,
+            RgbColourSpace,
+            RgbXyzConverter,
+            SRGB_TO_XYZ_MATRIX,
+            XYZ_TO_SRGB_MATRIX,
+        },
+        crate::models::color_model::test_utils::assert_close,
+    };
+
+    fn assert_matrix_close(actual: &Matrix, expected: &Matrix) {
+        for (actual_row, expected_row) in actual.iter().zip(expected) {
+            for (actual, expected) in actual_row.iter().zip(expected_row) {
+                assert_close(*actual, *expected, 5e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculate_srgb_matrices() {
+        let converter = RgbXyzConverter::calculate_matrices(&RgbColourSpace::SRGB);
+
+        assert_matrix_close(&converter.rgb_to_xyz, &SRGB_TO_XYZ_MATRIX);
+        assert_matrix_close(&converter.xyz_to_rgb, &XYZ_TO_SRGB_MATRIX);
+    }
+
+    #[test]
+    fn test_calculate_display_p3_matrices() {
+        let converter = RgbXyzConverter::calculate_matrices(&RgbColourSpace::DISPLAY_P3);
+
+        assert_matrix_close(
+            &converter.rgb_to_xyz,
+            &[
+                [0.486_571, 0.265_668, 0.198_217],
+                [0.228_975, 0.691_739, 0.079_287],
+                [0., 0.045_113, 1.043_944],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_calculate_adobe_rgb_matrices() {
+        let converter = RgbXyzConverter::calculate_matrices(&RgbColourSpace::ADOBE_RGB);
+
+        assert_matrix_close(
+            &converter.rgb_to_xyz,
+            &[
+                [0.576_731, 0.185_554, 0.188_185],
+                [0.297_377, 0.627_349, 0.075_274],
+                [0.027_034, 0.070_687, 0.991_108],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_calculate_prophoto_rgb_matrices() {
+        let converter = RgbXyzConverter::calculate_matrices(&RgbColourSpace::PROPHOTO_RGB);
+
+        assert_matrix_close(
+            &converter.rgb_to_xyz,
+            &[
+                [0.797_675, 0.135_192, 0.031_353],
+                [0.288_040, 0.711_874, 0.000_086],
+                [0., 0., 0.825_210],
+            ],
+        );
+    }
 }
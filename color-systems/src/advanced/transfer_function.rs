@@ -0,0 +1,195 @@
+/// The function relating an RGB colour space's encoded component values to
+/// linear light.
+///
+/// Every function is extended to negative values by mirroring it around zero,
+/// so that out-of-gamut components survive a round trip.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferFunction {
+    /// Components are already linear light.
+    Linear,
+    /// The piecewise sRGB curve (IEC 61966-2-1), also used by Display P3.
+    Srgb,
+    /// A pure power curve with the given exponent.
+    Gamma(f64),
+    /// The ITU-R BT.709 curve, also used by BT.2020.
+    Rec709,
+    /// The ROMM RGB curve used by ProPhoto RGB.
+    RommRgb,
+    /// The SMPTE ST 2084 perceptual quantizer used for HDR video, where a
+    /// linear value of `1` is 10,000 cd/m².
+    Pq,
+    /// The ITU-R BT.2100 hybrid log-gamma curve used for HDR video. Linear
+    /// values are scene light in `0..=1`, without the display's system gamma.
+    Hlg,
+}
+
+/// ST 2084 `m1`.
+const PQ_M1: f64 = 2610. / 16384.;
+/// ST 2084 `m2`.
+const PQ_M2: f64 = 2523. / 4096. * 128.;
+/// ST 2084 `c1`.
+const PQ_C1: f64 = 3424. / 4096.;
+/// ST 2084 `c2`.
+const PQ_C2: f64 = 2413. / 4096. * 32.;
+/// ST 2084 `c3`.
+const PQ_C3: f64 = 2392. / 4096. * 32.;
+
+/// BT.2100 HLG `a`.
+const HLG_A: f64 = 0.178_832_77;
+/// BT.2100 HLG `b`, `1 - 4a`.
+const HLG_B: f64 = 0.284_668_92;
+/// BT.2100 HLG `c`, `0.5 - a ln(4a)`.
+const HLG_C: f64 = 0.559_910_73;
+
+impl TransferFunction {
+    /// Decodes an encoded component into linear light.
+    #[inline]
+    #[must_use]
+    pub fn to_linear(self, encoded: f64) -> f64 {
+        let magnitude = encoded.abs();
+
+        let linear = match self {
+            Self::Linear => magnitude,
+            Self::Srgb => {
+                if magnitude <= 0.040_45 {
+                    magnitude / 12.92
+                } else {
+                    f64::powf((magnitude + 0.055) / 1.055, 2.4)
+                }
+            },
+            Self::Gamma(gamma) => f64::powf(magnitude, gamma),
+            Self::Rec709 => {
+                if magnitude < 0.081 {
+                    magnitude / 4.5
+                } else {
+                    f64::powf((magnitude + 0.099) / 1.099, 1. / 0.45)
+                }
+            },
+            Self::RommRgb => {
+                if magnitude < 16. / 512. {
+                    magnitude / 16.
+                } else {
+                    f64::powf(magnitude, 1.8)
+                }
+            },
+            Self::Pq => {
+                let power = f64::powf(magnitude, 1. / PQ_M2);
+
+                f64::powf(
+                    f64::max(power - PQ_C1, 0.) / PQ_C3.mul_add(-power, PQ_C2),
+                    1. / PQ_M1,
+                )
+            },
+            Self::Hlg => {
+                if magnitude <= 0.5 {
+                    magnitude * magnitude / 3.
+                } else {
+                    (((magnitude - HLG_C) / HLG_A).exp() + HLG_B) / 12.
+                }
+            },
+        };
+
+        linear.copysign(encoded)
+    }
+
+    /// Encodes a linear-light component.
+    #[inline]
+    #[must_use]
+    pub fn to_encoded(self, linear: f64) -> f64 {
+        let magnitude = linear.abs();
+
+        let encoded = match self {
+            Self::Linear => magnitude,
+            Self::Srgb => {
+                if magnitude <= 0.003_130_8 {
+                    magnitude * 12.92
+                } else {
+                    f64::powf(magnitude, 1. / 2.4).mul_add(1.055, -0.055)
+                }
+            },
+            Self::Gamma(gamma) => f64::powf(magnitude, 1. / gamma),
+            Self::Rec709 => {
+                if magnitude < 0.018 {
+                    magnitude * 4.5
+                } else {
+                    f64::powf(magnitude, 0.45).mul_add(1.099, -0.099)
+                }
+            },
+            Self::RommRgb => {
+                if magnitude < 1. / 512. {
+                    magnitude * 16.
+                } else {
+                    f64::powf(magnitude, 1. / 1.8)
+                }
+            },
+            Self::Pq => {
+                let power = f64::powf(magnitude, PQ_M1);
+
+                f64::powf(
+                    PQ_C2.mul_add(power, PQ_C1) / PQ_C3.mul_add(power, 1.),
+                    PQ_M2,
+                )
+            },
+            Self::Hlg => {
+                if magnitude <= 1. / 12. {
+                    (3. * magnitude).sqrt()
+                } else {
+                    HLG_A.mul_add(12f64.mul_add(magnitude, -HLG_B).ln(), HLG_C)
+                }
+            },
+        };
+
+        encoded.copysign(linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::TransferFunction,
+        crate::models::color_model::test_utils::assert_close,
+    };
+
+    #[test]
+    fn test_round_trip() {
+        for transfer_function in [
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(563. / 256.),
+            TransferFunction::Rec709,
+            TransferFunction::RommRgb,
+            TransferFunction::Hlg,
+        ] {
+            for value in [-0.5, 0., 0.001, 0.01, 0.02, 0.1, 0.5, 0.9, 1.] {
+                let round_trip = transfer_function.to_encoded(transfer_function.to_linear(value));
+
+                assert_close(round_trip, value, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_srgb_reference_values() {
+        assert_close(TransferFunction::Srgb.to_linear(0.5), 0.214_041, 1e-6);
+        assert_close(TransferFunction::Srgb.to_encoded(0.5), 0.735_357, 1e-6);
+    }
+
+    #[test]
+    fn test_hdr_reference_values() {
+        // 100 cd/m² and the PQ signal for it.
+        assert_close(TransferFunction::Pq.to_encoded(0.01), 0.508_078, 1e-6);
+        assert_close(TransferFunction::Pq.to_linear(1.), 1., 1e-9);
+        assert_close(TransferFunction::Pq.to_linear(0.), 0., 1e-12);
+
+        // PQ does not encode black as exactly 0.
+        for value in [0., 0.001, 0.01, 0.1, 0.5, 1.] {
+            let round_trip = TransferFunction::Pq.to_encoded(TransferFunction::Pq.to_linear(value));
+
+            assert_close(round_trip, value, 1e-6);
+        }
+
+        assert_close(TransferFunction::Hlg.to_encoded(1. / 12.), 0.5, 1e-9);
+        assert_close(TransferFunction::Hlg.to_encoded(1.), 1., 1e-6);
+        assert_close(TransferFunction::Hlg.to_linear(0.75), 0.264_963, 1e-6);
+    }
+}
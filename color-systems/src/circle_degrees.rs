@@ -1,31 +1,46 @@
-use std::ops::{
-    Add,
-    AddAssign,
-    Sub,
-    SubAssign,
+use {
+    crate::error::ColorError,
+    std::ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+    },
 };
 
+/// Creates a [`CircleDegrees`](crate::CircleDegrees), wrapping the value into
+/// `0..360`.
 #[macro_export]
 macro_rules! circle_degrees_wrapped {
     ($value:expr) => {
-        $crate::circle_degrees::CircleDegrees::new_wrapped($value)
+        $crate::CircleDegrees::new_wrapped($value)
     };
 }
 
+/// Creates a [`CircleDegrees`](crate::CircleDegrees) at compile time, failing
+/// to compile if the value is outside of `0..360`.
 #[macro_export]
 macro_rules! circle_degrees {
     ($value:expr) => {{
-        const OUTPUT: $crate::circle_degrees::CircleDegrees =
-            $crate::circle_degrees::CircleDegrees::new_exact_or_panic($value);
+        const OUTPUT: $crate::CircleDegrees = $crate::CircleDegrees::new_exact_or_panic($value);
         OUTPUT
     }};
 }
 
+/// A whole number of degrees in the range `0..360`.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "i16", into = "i16")
+)]
 pub struct CircleDegrees(i16);
 
 impl CircleDegrees {
+    /// Creates a new angle, returning `None` if it is outside of the range
+    /// `0..360`.
     #[inline]
+    #[must_use]
     pub const fn new_exact(with: i16) -> Option<Self> {
         match with {
             0..=359 => Some(Self(with)),
@@ -33,17 +48,40 @@ impl CircleDegrees {
         }
     }
 
+    /// Creates a new angle, failing if it is outside of the range `0..360`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::DegreesOutOfRange`] if the angle is outside of
+    /// `0..360`.
+    #[inline]
+    pub const fn try_new_exact(with: i16) -> Result<Self, ColorError> {
+        match Self::new_exact(with) {
+            Some(degrees) => Ok(degrees),
+            None => Err(ColorError::DegreesOutOfRange(with as f32)),
+        }
+    }
+
+    /// Creates a new angle.
+    ///
+    /// # Panics
+    /// Panics if the angle is outside of the range `0..360`.
+    #[must_use]
     pub const fn new_exact_or_panic(with: i16) -> Self {
         assert!(0 <= with && with < 360, "Value is not in range 0..360");
         Self(with)
     }
 
+    /// Creates a new angle, wrapping values outside of `0..360` around the
+    /// circle so that `-30` becomes `330`.
     #[inline]
+    #[must_use]
     pub const fn new_wrapped(with: i16) -> Self {
-        Self(with % 360)
+        Self(with.rem_euclid(360))
     }
 
+    /// The angle in degrees, in the range `0..360`.
     #[inline]
+    #[must_use]
     pub const fn value(&self) -> i16 {
         self.0
     }
@@ -81,6 +119,15 @@ impl SubAssign for CircleDegrees {
     }
 }
 
+impl TryFrom<i16> for CircleDegrees {
+    type Error = ColorError;
+
+    #[inline]
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        Self::try_new_exact(value)
+    }
+}
+
 impl From<CircleDegrees> for i16 {
     #[inline]
     fn from(value: CircleDegrees) -> Self {
@@ -0,0 +1,211 @@
+use {
+    crate::{
+        circle_degrees::CircleDegrees,
+        error::ColorError,
+        interpolation::HueInterpolation,
+    },
+    std::ops::{
+        Add,
+        AddAssign,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// Creates a [`CircleDegreesF32`](crate::CircleDegreesF32), wrapping the value
+/// into `0..360`.
+#[macro_export]
+macro_rules! circle_degrees_f32_wrapped {
+    ($value:expr) => {
+        $crate::CircleDegreesF32::new_wrapped($value)
+    };
+}
+
+/// Creates a [`CircleDegreesF32`](crate::CircleDegreesF32) at compile time,
+/// failing to compile if the value is outside of `0..360`.
+#[macro_export]
+macro_rules! circle_degrees_f32 {
+    ($value:expr) => {{
+        const OUTPUT: $crate::CircleDegreesF32 =
+            $crate::CircleDegreesF32::new_exact_or_panic($value);
+        OUTPUT
+    }};
+}
+
+/// An angle in degrees in the range `0..360`, stored with sub-degree
+/// precision.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f32", into = "f32")
+)]
+pub struct CircleDegreesF32(f32);
+
+impl CircleDegreesF32 {
+    /// Creates a new angle, returning `None` if it is outside of the range
+    /// `0..360`.
+    #[inline]
+    #[must_use]
+    pub const fn new_exact(with: f32) -> Option<Self> {
+        if 0. <= with && with < 360. {
+            Some(Self(with))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new angle, failing if it is outside of the range `0..360` or
+    /// is NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::DegreesOutOfRange`] if the angle is outside of
+    /// `0..360`.
+    #[inline]
+    pub const fn try_new_exact(with: f32) -> Result<Self, ColorError> {
+        match Self::new_exact(with) {
+            Some(degrees) => Ok(degrees),
+            None => Err(ColorError::DegreesOutOfRange(with)),
+        }
+    }
+
+    /// Creates a new angle.
+    ///
+    /// # Panics
+    /// Panics if the angle is outside of the range `0..360`.
+    #[must_use]
+    pub const fn new_exact_or_panic(with: f32) -> Self {
+        assert!(0. <= with && with < 360., "Value is not in range 0..360");
+        Self(with)
+    }
+
+    /// Creates a new angle, wrapping values outside of `0..360` around the
+    /// circle so that `-30` becomes `330`. Non-finite values are treated as
+    /// `0`.
+    #[inline]
+    #[must_use]
+    pub fn new_wrapped(with: f32) -> Self {
+        if !with.is_finite() {
+            return Self(0.);
+        }
+
+        let wrapped = with.rem_euclid(360.);
+
+        // Tiny negative values can round up to exactly 360.
+        if wrapped < 360. {
+            Self(wrapped)
+        } else {
+            Self(0.)
+        }
+    }
+
+    /// The angle in degrees, in the range `0..360`.
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> f32 {
+        self.0
+    }
+
+    /// The signed difference, in degrees, from this angle to `other` along the
+    /// shortest arc. The result is in the range `(-180, 180]`, where positive
+    /// values increase the hue.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn difference(self, other: Self) -> f32 {
+        HueInterpolation::Shorter.difference(f64::from(self.0), f64::from(other.0)) as f32
+    }
+
+    /// Interpolates between this angle and `other` along the shortest arc,
+    /// where a `t` of `0` gives this angle and `1` gives `other`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        HueInterpolation::Shorter.interpolate_degrees(Some(self), Some(other), t)
+    }
+}
+
+impl Add for CircleDegreesF32 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new_wrapped(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for CircleDegreesF32 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for CircleDegreesF32 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new_wrapped(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for CircleDegreesF32 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl TryFrom<f32> for CircleDegreesF32 {
+    type Error = ColorError;
+
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::try_new_exact(value)
+    }
+}
+
+impl From<CircleDegrees> for CircleDegreesF32 {
+    #[inline]
+    fn from(value: CircleDegrees) -> Self {
+        Self(f32::from(value.value()))
+    }
+}
+
+impl From<CircleDegreesF32> for f32 {
+    #[inline]
+    fn from(value: CircleDegreesF32) -> Self {
+        value.value()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use {
+        super::CircleDegreesF32,
+        crate::circle_degrees::CircleDegrees,
+    };
+
+    #[test]
+    fn test_new_wrapped() {
+        assert_eq!(CircleDegreesF32::new_wrapped(-30.).value(), 330.);
+        assert_eq!(CircleDegreesF32::new_wrapped(720.5).value(), 0.5);
+        assert_eq!(CircleDegreesF32::new_wrapped(-1e-6).value(), 0.);
+        assert_eq!(CircleDegreesF32::new_wrapped(f32::NAN).value(), 0.);
+        assert_eq!(CircleDegrees::new_wrapped(-30).value(), 330);
+    }
+
+    #[test]
+    fn test_shortest_arc() {
+        let a = circle_degrees_f32!(350.);
+        let b = circle_degrees_f32!(10.);
+
+        assert_eq!(a.difference(b), 20.);
+        assert_eq!(b.difference(a), -20.);
+        assert_eq!(a.lerp(b, 0.5).value(), 0.);
+        assert_eq!(a.lerp(b, 0.25).value(), 355.);
+        assert_eq!(b.lerp(a, 1.).value(), 350.);
+    }
+}
@@ -1,116 +1,473 @@
 use crate::{
-    circle_degrees::CircleDegrees,
-    models::color_model::ColorModel,
+    circle_degrees_f32::CircleDegreesF32,
+    error::ColorError,
+    models::color_model::{
+        ColorModel,
+        FromColorModel,
+    },
     percentage_f32::PercentageF32,
 };
 
+/// A colour stored in the model `C`.
+///
+/// The components of any other model can be read from a colour, and it can be
+/// converted into any other model, whatever `C` is. Components that cannot be
+/// calculated from `C` are reported by the `try_*` methods; the other methods
+/// panic instead.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct Color<C: ColorModel> {
+    /// The model that stores the components.
     pub(super) color: C,
 }
 
 impl<C: ColorModel> Color<C> {
+    /// Wraps a colour model, such as one defined outside of this crate.
+    #[inline]
+    pub const fn new(color: C) -> Self {
+        Self { color }
+    }
+
+    /// The model this colour is stored in.
+    #[inline]
+    pub const fn model(&self) -> &C {
+        &self.color
+    }
+
+    /// Unwraps the colour model.
+    #[inline]
+    pub fn into_model(self) -> C {
+        self.color
+    }
+
+    /// Converts the colour into the model `T`, such as
+    /// `colour.convert::<Oklch>()`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_convert`].
+    #[inline]
+    pub fn convert<T: FromColorModel>(&self) -> Color<T> {
+        self.try_convert().expect("Colour could not be converted")
+    }
+
+    /// Converts the colour into the model `T`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_convert<T: FromColorModel>(&self) -> Result<Color<T>, ColorError> {
+        T::try_from_color(self)
+    }
+
+    /// The red component in sRGB, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_red`].
     #[inline]
     pub fn red(&self) -> u8 {
+        self.try_red().expect("Red value was not returned")
+    }
+
+    /// The red component in sRGB, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_red(&self) -> Result<u8, ColorError> {
         let (r, ..) = self.color.select_rgb::<true, false, false>();
 
-        r.expect("Red value was not returned")
+        r.ok_or(ColorError::ComponentUnavailable("red"))
     }
 
+    /// The green component in sRGB, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_green`].
     #[inline]
     pub fn green(&self) -> u8 {
+        self.try_green().expect("Green value was not returned")
+    }
+
+    /// The green component in sRGB, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_green(&self) -> Result<u8, ColorError> {
         let (_, g, _) = self.color.select_rgb::<false, true, false>();
 
-        g.expect("Green value was not returned")
+        g.ok_or(ColorError::ComponentUnavailable("green"))
     }
 
+    /// The blue component in sRGB, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_blue`].
     #[inline]
     pub fn blue(&self) -> u8 {
+        self.try_blue().expect("Blue value was not returned")
+    }
+
+    /// The blue component in sRGB, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_blue(&self) -> Result<u8, ColorError> {
         let (.., b) = self.color.select_rgb::<false, false, true>();
 
-        b.expect("Blue value was not returned")
+        b.ok_or(ColorError::ComponentUnavailable("blue"))
     }
 
+    /// The red component in sRGB as a percentage.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_red_float`].
     #[inline]
     pub fn red_float(&self) -> PercentageF32 {
+        self.try_red_float().expect("Red value was not returned")
+    }
+
+    /// The red component in sRGB as a percentage.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_red_float(&self) -> Result<PercentageF32, ColorError> {
         let (r, ..) = self.color.select_rgb_float::<true, false, false>();
 
-        r.expect("Red value was not returned")
+        r.ok_or(ColorError::ComponentUnavailable("red"))
     }
 
+    /// The green component in sRGB as a percentage.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_green_float`].
     #[inline]
     pub fn green_float(&self) -> PercentageF32 {
+        self.try_green_float()
+            .expect("Green value was not returned")
+    }
+
+    /// The green component in sRGB as a percentage.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_green_float(&self) -> Result<PercentageF32, ColorError> {
         let (_, g, _) = self.color.select_rgb_float::<false, true, false>();
 
-        g.expect("Green value was not returned")
+        g.ok_or(ColorError::ComponentUnavailable("green"))
     }
 
+    /// The blue component in sRGB as a percentage.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_blue_float`].
     #[inline]
     pub fn blue_float(&self) -> PercentageF32 {
+        self.try_blue_float().expect("Blue value was not returned")
+    }
+
+    /// The blue component in sRGB as a percentage.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_blue_float(&self) -> Result<PercentageF32, ColorError> {
         let (.., b) = self.color.select_rgb_float::<false, false, true>();
 
-        b.expect("Red value was not returned")
+        b.ok_or(ColorError::ComponentUnavailable("blue"))
+    }
+
+    /// The hue of the sRGB colour, shared by HSL and HSV.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_hue`].
+    #[inline]
+    pub fn hue(&self) -> CircleDegreesF32 {
+        self.try_hue().expect("Hue value was not returned")
     }
 
+    /// The hue of the sRGB colour, shared by HSL and HSV.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
     #[inline]
-    pub fn hue(&self) -> CircleDegrees {
+    pub fn try_hue(&self) -> Result<CircleDegreesF32, ColorError> {
         let (h, ..) = self.color.select_hsv::<true, false, false>();
 
-        h.expect("Hue value was not returned")
+        h.ok_or(ColorError::ComponentUnavailable("hue"))
     }
 
+    /// The HSV saturation of the sRGB colour.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_saturation_hsv`].
     #[inline]
     pub fn saturation_hsv(&self) -> PercentageF32 {
+        self.try_saturation_hsv()
+            .expect("Saturation value was not returned")
+    }
+
+    /// The HSV saturation of the sRGB colour.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_saturation_hsv(&self) -> Result<PercentageF32, ColorError> {
         let (_, s, _) = self.color.select_hsv::<false, true, false>();
 
-        s.expect("Saturation value was not returned")
+        s.ok_or(ColorError::ComponentUnavailable("saturation"))
     }
 
+    /// The HSL saturation of the sRGB colour.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_saturation_hsl`].
     #[inline]
     pub fn saturation_hsl(&self) -> PercentageF32 {
+        self.try_saturation_hsl()
+            .expect("Saturation value was not returned")
+    }
+
+    /// The HSL saturation of the sRGB colour.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_saturation_hsl(&self) -> Result<PercentageF32, ColorError> {
         let (_, s, _) = self.color.select_hsl::<false, true, false>();
 
-        s.expect("Saturation value was not returned")
+        s.ok_or(ColorError::ComponentUnavailable("saturation"))
     }
 
+    /// The HSV value (brightness) of the sRGB colour.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_hsv_value`].
     #[inline]
     pub fn hsv_value(&self) -> PercentageF32 {
+        self.try_hsv_value().expect("Value value was not returned")
+    }
+
+    /// The HSV value (brightness) of the sRGB colour.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_hsv_value(&self) -> Result<PercentageF32, ColorError> {
         let (.., v) = self.color.select_hsv::<false, false, true>();
 
-        v.expect("Value value was not returned")
+        v.ok_or(ColorError::ComponentUnavailable("value"))
     }
 
+    /// The HSL luminosity (lightness) of the sRGB colour. This is not the
+    /// luminance used for contrast; see [`Self::relative_luminance`].
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_luminosity`].
     #[inline]
     pub fn luminosity(&self) -> PercentageF32 {
+        self.try_luminosity().expect("Luminosity was not returned")
+    }
+
+    /// The HSL luminosity (lightness) of the sRGB colour.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_luminosity(&self) -> Result<PercentageF32, ColorError> {
         let (.., l) = self.color.select_hsl::<false, false, true>();
 
-        l.expect("Luminosity was not returned")
+        l.ok_or(ColorError::ComponentUnavailable("luminosity"))
     }
 
+    /// The cyan component in CMYK, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_cyan`].
     #[inline]
     pub fn cyan(&self) -> u8 {
+        self.try_cyan().expect("Cyan value was not returned")
+    }
+
+    /// The cyan component in CMYK, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_cyan(&self) -> Result<u8, ColorError> {
         let (c, ..) = self.color.select_cmyk::<true, false, false, false>();
 
-        c.expect("Cyan value was not returned")
+        c.ok_or(ColorError::ComponentUnavailable("cyan"))
     }
 
+    /// The magenta component in CMYK, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_magenta`].
     #[inline]
     pub fn magenta(&self) -> u8 {
+        self.try_magenta().expect("Magenta value was not returned")
+    }
+
+    /// The magenta component in CMYK, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_magenta(&self) -> Result<u8, ColorError> {
         let (_, m, ..) = self.color.select_cmyk::<false, true, false, false>();
 
-        m.expect("Magenta value was not returned")
+        m.ok_or(ColorError::ComponentUnavailable("magenta"))
     }
 
+    /// The yellow component in CMYK, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_yellow`].
     #[inline]
     pub fn yellow(&self) -> u8 {
+        self.try_yellow().expect("Yellow value was not returned")
+    }
+
+    /// The yellow component in CMYK, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_yellow(&self) -> Result<u8, ColorError> {
         let (_, _, y, _) = self.color.select_cmyk::<false, false, true, false>();
 
-        y.expect("Yellow value was not returned")
+        y.ok_or(ColorError::ComponentUnavailable("yellow"))
     }
 
+    /// The key (black) component in CMYK, from `0` to `255`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_key_black`].
     #[inline]
     pub fn key_black(&self) -> u8 {
+        self.try_key_black().expect("Key value was not returned")
+    }
+
+    /// The key (black) component in CMYK, from `0` to `255`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_key_black(&self) -> Result<u8, ColorError> {
         let (.., k) = self.color.select_cmyk::<false, false, false, true>();
 
-        k.expect("Key value was not returned")
+        k.ok_or(ColorError::ComponentUnavailable("key/black"))
+    }
+
+    /// The CIE XYZ `X` tristimulus value, relative to D65 with a `Y` of `1` for
+    /// white.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_x`].
+    #[inline]
+    pub fn x(&self) -> f64 {
+        self.try_x().expect("X value was not returned")
+    }
+
+    /// The CIE XYZ `X` tristimulus value, relative to D65 with a `Y` of `1` for
+    /// white.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_x(&self) -> Result<f64, ColorError> {
+        let (x, ..) = self.color.select_xyz::<true, false, false>();
+
+        x.ok_or(ColorError::ComponentUnavailable("X"))
+    }
+
+    /// The CIE XYZ `Y` tristimulus value (relative luminance), where white is
+    /// `1`.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_y`].
+    #[inline]
+    pub fn y(&self) -> f64 {
+        self.try_y().expect("Y value was not returned")
+    }
+
+    /// The CIE XYZ `Y` tristimulus value (relative luminance), where white is
+    /// `1`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_y(&self) -> Result<f64, ColorError> {
+        let (_, y, _) = self.color.select_xyz::<false, true, false>();
+
+        y.ok_or(ColorError::ComponentUnavailable("Y"))
+    }
+
+    /// The CIE XYZ `Z` tristimulus value, relative to D65 with a `Y` of `1` for
+    /// white.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_z`].
+    #[inline]
+    pub fn z(&self) -> f64 {
+        self.try_z().expect("Z value was not returned")
+    }
+
+    /// The CIE XYZ `Z` tristimulus value, relative to D65 with a `Y` of `1` for
+    /// white.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the value.
+    #[inline]
+    pub fn try_z(&self) -> Result<f64, ColorError> {
+        let (.., z) = self.color.select_xyz::<false, false, true>();
+
+        z.ok_or(ColorError::ComponentUnavailable("Z"))
     }
 }
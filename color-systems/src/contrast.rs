@@ -0,0 +1,668 @@
+//! Contrast between text and background colours, for accessibility checks.
+//!
+//! Both the WCAG 2.x contrast ratio and the APCA lightness contrast (`Lc`)
+//! proposed for WCAG 3 are available:
+//!
+//! ```
+//! use color_systems::{
+//!     contrast::{
+//!         self,
+//!         TextSize,
+//!         WcagLevel,
+//!     },
+//!     prelude::*,
+//! };
+//!
+//! let text = Color::new_rgb(118, 118, 118);
+//! let background = Color::new_rgb(255, 255, 255);
+//!
+//! assert!((text.contrast_ratio(&background) - 4.54).abs() < 0.01);
+//! assert_eq!(
+//!     text.wcag_level(&background, TextSize::Normal),
+//!     Some(WcagLevel::Aa)
+//! );
+//! assert_eq!(
+//!     text.wcag_level(&background, TextSize::Large),
+//!     Some(WcagLevel::Aaa)
+//! );
+//!
+//! let lc = text.apca_contrast(&background);
+//!
+//! assert!((lc - 71.57).abs() < 0.01);
+//! assert_eq!(contrast::apca_minimum_font_size(lc, 400), Some(24.));
+//! ```
+
+use crate::{
+    advanced::TransferFunction,
+    color::Color,
+    error::ColorError,
+    models::color_model::{
+        ColorModel,
+        FromColorModel,
+    },
+};
+
+/// The size of text, which changes the contrast that WCAG requires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextSize {
+    /// Text smaller than large text.
+    Normal,
+    /// Text of at least 18 point, or 14 point bold.
+    Large,
+}
+
+/// A WCAG 2.x conformance level for the contrast of text (success criteria
+/// 1.4.3 and 1.4.6).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    /// The minimum contrast: 4.5:1, or 3:1 for large text.
+    Aa,
+    /// The enhanced contrast: 7:1, or 4.5:1 for large text.
+    Aaa,
+}
+
+impl WcagLevel {
+    /// The lowest contrast ratio that meets this level for text of `size`.
+    #[inline]
+    #[must_use]
+    pub const fn minimum_contrast_ratio(self, size: TextSize) -> f64 {
+        match (self, size) {
+            (Self::Aa, TextSize::Large) => 3.,
+            (Self::Aa, TextSize::Normal) | (Self::Aaa, TextSize::Large) => 4.5,
+            (Self::Aaa, TextSize::Normal) => 7.,
+        }
+    }
+}
+
+/// A model whose lightness can be changed without changing its hue, used by
+/// [`Color::ensure_contrast`].
+pub trait AdjustLightness: FromColorModel + Clone {
+    /// The lightness, scaled so that `0` is black and `1` is white.
+    fn relative_lightness(&self) -> f64;
+
+    /// The same colour with the lightness replaced, scaled so that `0` is
+    /// black and `1` is white. Every other component is kept.
+    #[must_use]
+    fn with_relative_lightness(&self, lightness: f64) -> Self;
+
+    /// The same colour with its chroma, or saturation, multiplied by `scale`
+    /// in `0..=1`. The lightness and hue are kept.
+    #[must_use]
+    fn with_scaled_chroma(&self, scale: f64) -> Self;
+}
+
+/// Halving a search range this many times finds the value to within `1e-6`.
+const SEARCH_ITERATIONS: usize = 20;
+
+/// How far outside of `0..=1` a linear sRGB component can be and still be
+/// treated as inside the gamut, to allow for rounding.
+const GAMUT_TOLERANCE: f64 = 1e-5;
+
+/// Reduces the chroma of `colour` as little as possible to bring it inside
+/// the sRGB gamut, keeping its lightness and hue, as in CSS Color 4 gamut
+/// mapping.
+fn into_srgb_gamut<M: AdjustLightness>(colour: M) -> M {
+    let in_gamut = |colour: &M| {
+        Color::new(colour.clone())
+            .to_linear_rgb()
+            .linear_components()
+            .iter()
+            .all(|c| (-GAMUT_TOLERANCE..=1. + GAMUT_TOLERANCE).contains(c))
+    };
+
+    if in_gamut(&colour) {
+        return colour;
+    }
+
+    // A grey at the same lightness is always inside the gamut.
+    let (mut inside, mut outside) = (0., 1.);
+
+    for _ in 0..SEARCH_ITERATIONS {
+        let middle = f64::midpoint(inside, outside);
+
+        if in_gamut(&colour.with_scaled_chroma(middle)) {
+            inside = middle;
+        } else {
+            outside = middle;
+        }
+    }
+
+    colour.with_scaled_chroma(inside)
+}
+
+/// A font that text must be at least as large and as heavy as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FontRequirement {
+    /// The CSS font weight, such as `400` for normal or `700` for bold.
+    pub weight: u16,
+    /// The font size in CSS pixels.
+    pub size:   f32,
+}
+
+/// Shorthand for the rows of [`APCA_FONT_REQUIREMENTS`].
+const fn font(weight: u16, size: f32) -> FontRequirement {
+    FontRequirement { weight, size }
+}
+
+/// The fonts allowed at each `Lc` level of APCA's bronze simple mode, from
+/// the highest level down.
+const APCA_FONT_REQUIREMENTS: [(f64, &[FontRequirement]); 4] = [
+    (90., &[font(200, 24.), font(300, 18.), font(400, 14.)]),
+    (
+        75.,
+        &[
+            font(300, 24.),
+            font(400, 18.),
+            font(500, 16.),
+            font(700, 14.),
+        ],
+    ),
+    (
+        60.,
+        &[
+            font(200, 48.),
+            font(300, 36.),
+            font(400, 24.),
+            font(500, 21.),
+            font(600, 18.),
+            font(700, 16.),
+        ],
+    ),
+    (45., &[font(400, 36.), font(700, 24.)]),
+];
+
+/// The fonts that readable text may use at the APCA lightness contrast `lc`.
+///
+/// This follows APCA's bronze simple mode with a reference font like
+/// Helvetica. Text may use any of the fonts, or one that is larger or heavier.
+///
+/// Only the magnitude of `lc` matters. Below `Lc 45` no text is readable
+/// enough, and the list is empty; `Lc 30` is the minimum for placeholder or
+/// disabled text and solid icons, and `Lc 15` for dividers and other
+/// non-text.
+#[must_use]
+pub fn apca_font_requirements(lc: f64) -> &'static [FontRequirement] {
+    APCA_FONT_REQUIREMENTS
+        .iter()
+        .find(|(minimum, _)| lc.abs() >= *minimum)
+        .map_or(&[], |(_, fonts)| fonts)
+}
+
+/// The smallest font size in CSS pixels that readable text of `weight` may use
+/// at the APCA lightness contrast `lc`, or `None` if no size is enough. See
+/// [`apca_font_requirements`].
+#[must_use]
+pub fn apca_minimum_font_size(lc: f64, weight: u16) -> Option<f32> {
+    apca_font_requirements(lc)
+        .iter()
+        .filter(|font| font.weight <= weight)
+        .map(|font| font.size)
+        .reduce(f32::min)
+}
+
+/// APCA 0.0.98G screen luminance, with the soft clamp applied near black.
+fn apca_luminance<C: ColorModel>(colour: &Color<C>) -> f64 {
+    const BLACK_THRESHOLD: f64 = 0.022;
+    const BLACK_CLAMP: f64 = 1.414;
+
+    let [red, green, blue] = [
+        colour.red_float(),
+        colour.green_float(),
+        colour.blue_float(),
+    ]
+    .map(|c| f64::from(c.value()).powf(2.4));
+
+    let luminance = 0.072_175f64.mul_add(blue, 0.212_672_9f64.mul_add(red, 0.715_152_2 * green));
+
+    if luminance < BLACK_THRESHOLD {
+        luminance + (BLACK_THRESHOLD - luminance).powf(BLACK_CLAMP)
+    } else {
+        luminance
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// The WCAG 2.x relative luminance of the colour, from `0` for black to
+    /// `1` for white.
+    ///
+    /// This is calculated from the colour clamped into the sRGB gamut, and
+    /// ignores any alpha. It is not the same as [`Self::luminosity`], which is
+    /// HSL lightness.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the value; see
+    /// [`Self::try_relative_luminance`].
+    #[inline]
+    pub fn relative_luminance(&self) -> f64 {
+        self.try_relative_luminance()
+            .expect("Relative luminance was not returned")
+    }
+
+    /// The WCAG 2.x relative luminance of the colour, from `0` for black to
+    /// `1` for white.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the sRGB components.
+    pub fn try_relative_luminance(&self) -> Result<f64, ColorError> {
+        let (Some(red), Some(green), Some(blue)) =
+            self.color.select_rgb_float::<true, true, true>()
+        else {
+            return Err(ColorError::ComponentUnavailable("relative luminance"));
+        };
+
+        let [red, green, blue] =
+            [red, green, blue].map(|c| TransferFunction::Srgb.to_linear(f64::from(c.value())));
+
+        Ok(0.0722f64.mul_add(blue, 0.2126f64.mul_add(red, 0.7152 * green)))
+    }
+
+    /// The WCAG 2.x contrast ratio between this colour and `other`, from `1`
+    /// for identical luminances to `21` for black and white. The order of the
+    /// colours does not matter.
+    ///
+    /// # Panics
+    /// Panics if either model cannot provide the sRGB components; see
+    /// [`Self::try_relative_luminance`].
+    #[inline]
+    pub fn contrast_ratio(&self, other: &Color<impl ColorModel>) -> f64 {
+        let [lighter, darker] = {
+            let this = self.relative_luminance();
+            let other = other.relative_luminance();
+
+            [this.max(other), this.min(other)]
+        };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Whether text in this colour on `background`, or the other way around,
+    /// meets `level` for text of `size`. The ratio is not rounded, so `4.499`
+    /// does not meet 4.5:1.
+    ///
+    /// # Panics
+    /// Panics if either model cannot provide the sRGB components; see
+    /// [`Self::try_relative_luminance`].
+    #[inline]
+    pub fn meets_wcag(
+        &self,
+        background: &Color<impl ColorModel>,
+        level: WcagLevel,
+        size: TextSize,
+    ) -> bool {
+        self.contrast_ratio(background) >= level.minimum_contrast_ratio(size)
+    }
+
+    /// The highest level that text in this colour on `background` meets for
+    /// text of `size`, or `None` if it does not meet AA.
+    ///
+    /// # Panics
+    /// Panics if either model cannot provide the sRGB components; see
+    /// [`Self::try_relative_luminance`].
+    #[inline]
+    pub fn wcag_level(
+        &self,
+        background: &Color<impl ColorModel>,
+        size: TextSize,
+    ) -> Option<WcagLevel> {
+        let ratio = self.contrast_ratio(background);
+
+        [WcagLevel::Aaa, WcagLevel::Aa]
+            .into_iter()
+            .find(|level| ratio >= level.minimum_contrast_ratio(size))
+    }
+
+    /// The colour nearest to this one, changing only its lightness in the
+    /// model `M`, whose WCAG 2.x contrast ratio with `background` is at least
+    /// `target_ratio`. Returns `None` if no lightness reaches the target.
+    ///
+    /// The hue of `M` is kept. At each lightness, the chroma or saturation is
+    /// reduced only as far as needed to keep the colour inside the sRGB gamut,
+    /// so the result displays with the hue and contrast it reports. Rounding
+    /// the result to 8-bit RGB can lower the contrast very slightly.
+    ///
+    /// # Panics
+    /// Panics if either colour cannot be converted to `M` or sRGB.
+    pub fn ensure_contrast<M: AdjustLightness>(
+        &self,
+        background: &Color<impl ColorModel>,
+        target_ratio: f64,
+    ) -> Option<Color<M>> {
+        let colour = self.convert::<M>().color;
+        let meets = |lightness: f64| {
+            let candidate = Color::new(into_srgb_gamut(colour.with_relative_lightness(lightness)));
+
+            (candidate.contrast_ratio(background) >= target_ratio).then_some(candidate)
+        };
+
+        let start = colour.relative_lightness().clamp(0., 1.);
+
+        if let Some(candidate) = meets(start) {
+            return Some(candidate);
+        }
+
+        // Search towards white and towards black, keeping whichever meets the
+        // target closest to the original lightness.
+        [1., 0.]
+            .into_iter()
+            .filter_map(|end: f64| {
+                let mut found = meets(end)?;
+                let (mut failing, mut passing) = (start, end);
+
+                for _ in 0..SEARCH_ITERATIONS {
+                    let middle = f64::midpoint(failing, passing);
+
+                    match meets(middle) {
+                        Some(candidate) => {
+                            found = candidate;
+                            passing = middle;
+                        },
+                        None => failing = middle,
+                    }
+                }
+
+                Some(((passing - start).abs(), found))
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, found)| found)
+    }
+
+    /// The APCA 0.0.98G lightness contrast (`Lc`) of text in this colour on
+    /// `background`, from about `106` for black on white to about `-108` for
+    /// white on black. Positive values are dark text on a light background.
+    ///
+    /// Unlike the WCAG 2.x ratio, the order of the colours matters. Alpha is
+    /// ignored.
+    ///
+    /// # Panics
+    /// Panics if either model cannot provide the sRGB components.
+    pub fn apca_contrast(&self, background: &Color<impl ColorModel>) -> f64 {
+        const SCALE: f64 = 1.14;
+        const OFFSET: f64 = 0.027;
+        const LOW_CLIP: f64 = 0.1;
+        const MINIMUM_DIFFERENCE: f64 = 0.0005;
+
+        let text = apca_luminance(self);
+        let background = apca_luminance(background);
+
+        if (background - text).abs() < MINIMUM_DIFFERENCE {
+            return 0.;
+        }
+
+        let contrast = if background > text {
+            let contrast = (background.powf(0.56) - text.powf(0.57)) * SCALE;
+
+            if contrast < LOW_CLIP {
+                0.
+            } else {
+                contrast - OFFSET
+            }
+        } else {
+            let contrast = (background.powf(0.65) - text.powf(0.62)) * SCALE;
+
+            if contrast > -LOW_CLIP {
+                0.
+            } else {
+                contrast + OFFSET
+            }
+        };
+
+        contrast * 100.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            TextSize,
+            WcagLevel,
+            apca_font_requirements,
+            apca_minimum_font_size,
+        },
+        crate::{
+            color::Color,
+            interpolation::HueInterpolation,
+            models::{
+                Hsl,
+                Lab,
+                Lch,
+                Oklab,
+                Oklch,
+                Rgb,
+                color_model::test_utils::assert_close,
+            },
+            p32,
+        },
+    };
+
+    /// The difference between the Oklch hues of two colours, in degrees.
+    fn oklch_hue_shift(from: Color<Rgb>, to: Color<Rgb>) -> f64 {
+        HueInterpolation::Shorter.difference(from.hue_oklch(), to.hue_oklch())
+    }
+
+    #[test]
+    fn test_relative_luminance() {
+        assert_close(Color::new_rgb(0, 0, 0).relative_luminance(), 0., 1e-3);
+        assert_close(Color::new_rgb(255, 255, 255).relative_luminance(), 1., 1e-3);
+        assert_close(Color::new_rgb(255, 0, 0).relative_luminance(), 0.2126, 1e-3);
+        assert_close(Color::new_rgb(0, 255, 0).relative_luminance(), 0.7152, 1e-3);
+        assert_close(Color::new_rgb(0, 0, 255).relative_luminance(), 0.0722, 1e-3);
+        assert_close(
+            Color::new_rgb(128, 128, 128).relative_luminance(),
+            0.215_861,
+            1e-3,
+        );
+
+        let grey = Color::new_rgb(128, 128, 128);
+
+        assert_close(
+            grey.to_oklch().relative_luminance(),
+            grey.relative_luminance(),
+            1e-3,
+        );
+        assert_close(f64::from(grey.luminosity().value()), 0.502, 1e-3);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+
+        assert_close(black.contrast_ratio(&white), 21., 1e-3);
+        assert_close(white.contrast_ratio(&black), 21., 1e-3);
+        assert_close(white.contrast_ratio(&white.to_lab()), 1., 1e-3);
+        assert_close(
+            Color::new_rgb(0x1a, 0x2b, 0x3c).contrast_ratio(&Color::new_rgb(0xf0, 0xe6, 0x8c)),
+            11.275,
+            1e-3,
+        );
+
+        // Alpha is ignored.
+        assert_close(
+            black.with_alpha(p32!(0.1)).contrast_ratio(&white),
+            21.,
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn test_wcag_levels() {
+        let white = Color::new_rgb(255, 255, 255);
+
+        for (grey, normal, large) in [
+            (0x00, Some(WcagLevel::Aaa), Some(WcagLevel::Aaa)),
+            (0x59, Some(WcagLevel::Aaa), Some(WcagLevel::Aaa)),
+            (0x5a, Some(WcagLevel::Aa), Some(WcagLevel::Aaa)),
+            (0x76, Some(WcagLevel::Aa), Some(WcagLevel::Aaa)),
+            (0x77, None, Some(WcagLevel::Aa)),
+            (0x94, None, Some(WcagLevel::Aa)),
+            (0x95, None, None),
+        ] {
+            let text = Color::new_rgb(grey, grey, grey);
+
+            assert_eq!(
+                text.wcag_level(&white, TextSize::Normal),
+                normal,
+                "{grey:#x}"
+            );
+            assert_eq!(text.wcag_level(&white, TextSize::Large), large, "{grey:#x}");
+        }
+
+        let text = Color::new_rgb(0x76, 0x76, 0x76);
+
+        assert!(text.meets_wcag(&white, WcagLevel::Aa, TextSize::Normal));
+        assert!(!text.meets_wcag(&white, WcagLevel::Aaa, TextSize::Normal));
+    }
+
+    #[test]
+    fn test_apca_contrast() {
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+        let grey = Color::new_rgb(0x88, 0x88, 0x88);
+
+        assert_close(black.apca_contrast(&white), 106.040_67, 1e-3);
+        assert_close(white.apca_contrast(&black), -107.884_73, 1e-3);
+        assert_close(grey.apca_contrast(&white), 63.056_47, 1e-3);
+        assert_close(white.apca_contrast(&grey), -68.541_46, 1e-3);
+        assert_close(
+            Color::new_rgb(0x1a, 0x2b, 0x3c).apca_contrast(&Color::new_rgb(0xf0, 0xe6, 0x8c)),
+            84.855_02,
+            1e-3,
+        );
+        assert_close(
+            grey.to_oklch().apca_contrast(&white.to_lab()),
+            63.056_47,
+            1e-3,
+        );
+
+        // Nearly identical colours, and low contrasts, are clipped to 0.
+        assert_close(grey.apca_contrast(&grey), 0., 1e-3);
+        assert_close(
+            Color::new_rgb(0x77, 0x77, 0x77).apca_contrast(&Color::new_rgb(0x78, 0x78, 0x78)),
+            0.,
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn test_apca_font_lookup() {
+        assert_eq!(apca_minimum_font_size(106., 400), Some(14.));
+        assert_eq!(apca_minimum_font_size(-80., 400), Some(18.));
+        assert_eq!(apca_minimum_font_size(80., 600), Some(16.));
+        assert_eq!(apca_minimum_font_size(80., 900), Some(14.));
+        assert_eq!(apca_minimum_font_size(63., 400), Some(24.));
+        assert_eq!(apca_minimum_font_size(63., 100), None);
+        assert_eq!(apca_minimum_font_size(50., 500), Some(36.));
+        assert_eq!(apca_minimum_font_size(50., 300), None);
+        assert_eq!(apca_minimum_font_size(44.9, 900), None);
+        assert!(apca_font_requirements(30.).is_empty());
+        assert_eq!(apca_font_requirements(45.).len(), 2);
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let white = Color::new_rgb(255, 255, 255);
+        let black = Color::new_rgb(0, 0, 0);
+        let orange = Color::new_rgb(255, 165, 0);
+
+        let darker = orange
+            .ensure_contrast::<Oklch>(&white, 4.5)
+            .expect("No colour met 4.5:1");
+
+        assert!(darker.contrast_ratio(&white) >= 4.5);
+        assert!(darker.contrast_ratio(&white) < 4.51);
+        assert_close(oklch_hue_shift(orange, darker.to_rgb()), 0., 2.);
+
+        let lighter = Color::new_rgb(0, 0, 139)
+            .ensure_contrast::<Hsl>(&black, 7.)
+            .expect("No colour met 7:1");
+
+        assert!(lighter.contrast_ratio(&black) >= 7.);
+        assert!(lighter.contrast_ratio(&black) < 7.01);
+        assert_close(f64::from(lighter.hue().value()), 240., 1e-3);
+
+        for adjusted in [
+            orange
+                .ensure_contrast::<Lab>(&white, 3.)
+                .map(|c| c.contrast_ratio(&white)),
+            orange
+                .ensure_contrast::<Lch>(&white, 3.)
+                .map(|c| c.contrast_ratio(&white)),
+            orange
+                .ensure_contrast::<Oklab>(&white, 3.)
+                .map(|c| c.contrast_ratio(&white)),
+        ] {
+            let ratio = adjusted.expect("No colour met 3:1");
+
+            assert!((3. ..3.01).contains(&ratio), "Unexpected ratio {ratio}");
+        }
+    }
+
+    #[test]
+    fn test_ensure_contrast_stays_in_gamut() {
+        let white = Color::new_rgb(255, 255, 255);
+        let black = Color::new_rgb(0, 0, 0);
+
+        for (colour, background, target) in [
+            (Color::new_rgb(255, 165, 0), white, 7.),
+            (Color::new_rgb(0, 0, 255), black, 7.),
+            (Color::new_rgb(0, 0, 255), black, 15.),
+            (Color::new_rgb(0, 200, 0), white, 4.5),
+        ] {
+            let adjusted = colour
+                .ensure_contrast::<Oklch>(&background, target)
+                .expect("No colour met the target");
+
+            for c in adjusted.to_linear_rgb().linear_components() {
+                assert!((-1e-4..=1. + 1e-4).contains(&c), "Out of gamut {c}");
+            }
+
+            let rgb = adjusted.to_rgb();
+
+            assert!(rgb.contrast_ratio(&background) >= target - 0.05);
+            assert_close(oklch_hue_shift(colour, rgb), 0., 2.);
+        }
+
+        let lab = Color::new_rgb(255, 165, 0)
+            .ensure_contrast::<Lch>(&white, 7.)
+            .expect("No colour met 7:1")
+            .to_rgb();
+
+        assert_close(
+            lab.to_lch().hue_lch(),
+            Color::new_rgb(255, 165, 0).to_lch().hue_lch(),
+            1.,
+        );
+    }
+
+    #[test]
+    fn test_ensure_contrast_picks_the_nearest_direction() {
+        let background = Color::new_rgb(0x76, 0x76, 0x76);
+
+        let light = Color::new_rgb(0xa0, 0x80, 0x80)
+            .ensure_contrast::<Oklch>(&background, 2.)
+            .expect("No colour met 2:1");
+        let dark = Color::new_rgb(0x60, 0x50, 0x50)
+            .ensure_contrast::<Oklch>(&background, 2.)
+            .expect("No colour met 2:1");
+
+        assert!(light.relative_luminance() > background.relative_luminance());
+        assert!(dark.relative_luminance() < background.relative_luminance());
+    }
+
+    #[test]
+    fn test_ensure_contrast_returns_none_when_impossible() {
+        let grey = Color::new_rgb(0x77, 0x77, 0x77);
+
+        assert!(grey.ensure_contrast::<Oklch>(&grey, 7.).is_none());
+        assert!(grey.ensure_contrast::<Hsl>(&grey, 22.).is_none());
+
+        // Colours that already meet the target are returned unchanged.
+        let black = Color::new_rgb(0, 0, 0)
+            .ensure_contrast::<Hsl>(&Color::new_rgb(255, 255, 255), 4.5)
+            .expect("Black did not meet 4.5:1");
+
+        assert_close(f64::from(black.luminosity().value()), 0., 1e-6);
+    }
+}
@@ -0,0 +1,14 @@
+//! Parsing and writing colours as CSS Color Level 4 strings.
+
+/// The CSS named colours.
+mod named;
+/// Parsing CSS colour strings.
+mod parse;
+/// Writing colours as CSS.
+mod serialize;
+
+pub(crate) use serialize::CssValue;
+pub use serialize::{
+    CssFormatter,
+    ToCss,
+};
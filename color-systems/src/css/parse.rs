@@ -0,0 +1,633 @@
+use {
+    super::named,
+    crate::{
+        advanced::{
+            ChromaticAdaptation,
+            D50_STANDARD_ILLUMINANT,
+            MatrixRow,
+            RgbColourSpace,
+            RgbXyzConverter,
+        },
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        error::ColorError,
+        models::{
+            Alpha,
+            AnyColor,
+            Rgb,
+            Xyz,
+            parse_hex_digits,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::{
+        f64::consts::PI,
+        str::FromStr,
+    },
+};
+
+/// A colour parsed from CSS, in the model it was written in.
+struct ParsedColor {
+    /// The colour, in the model named by the CSS function.
+    model: AnyColor,
+    /// The alpha, which is `1` when it was not given.
+    alpha: PercentageF32,
+}
+
+/// A single argument of a CSS colour function.
+enum Component {
+    /// A plain number.
+    Number(f64),
+    /// A percentage, as written (so `50%` is `50`).
+    Percentage(f64),
+    /// An angle, converted to degrees.
+    Degrees(f64),
+    /// The `none` keyword.
+    None,
+}
+
+impl Component {
+    /// Parses a single argument.
+    fn parse(token: &str) -> Result<Self, ColorError> {
+        if token == "none" {
+            return Ok(Self::None);
+        }
+
+        if let Some(percentage) = token.strip_suffix('%') {
+            return parse_number(percentage).map(Self::Percentage);
+        }
+
+        // `grad` must be checked before `rad`.
+        for (unit, scale) in [
+            ("deg", 1.),
+            ("grad", 0.9),
+            ("rad", 180. / PI),
+            ("turn", 360.),
+        ] {
+            if let Some(angle) = token.strip_suffix(unit) {
+                return parse_number(angle).map(|angle| Self::Degrees(angle * scale));
+            }
+        }
+
+        parse_number(token).map(Self::Number)
+    }
+
+    /// Resolves a number or percentage, where `100%` is `percent_reference`.
+    fn resolve(self, percent_reference: f64) -> Result<f64, ColorError> {
+        match self {
+            Self::Number(number) => Ok(number),
+            Self::Percentage(percentage) => Ok(percentage / 100. * percent_reference),
+            Self::None => Ok(0.),
+            Self::Degrees(_) => Err(ColorError::InvalidSyntax("unexpected angle")),
+        }
+    }
+
+    /// Whether the component was written as a percentage.
+    const fn is_percentage(&self) -> bool {
+        matches!(self, Self::Percentage(_))
+    }
+
+    /// Interprets the component as a hue in degrees.
+    const fn resolve_hue(self) -> Result<f64, ColorError> {
+        match self {
+            Self::Number(degrees) | Self::Degrees(degrees) => Ok(degrees),
+            Self::None => Ok(0.),
+            Self::Percentage(_) => Err(ColorError::InvalidSyntax("hue cannot be a percentage")),
+        }
+    }
+}
+
+/// Parses a finite number.
+fn parse_number(token: &str) -> Result<f64, ColorError> {
+    token
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+        .ok_or(ColorError::InvalidSyntax("invalid number"))
+}
+
+/// Trims the token, failing if it contains more than one word.
+fn single_token(token: &str) -> Result<&str, ColorError> {
+    let token = token.trim();
+
+    if token.is_empty() || token.contains(char::is_whitespace) {
+        Err(ColorError::InvalidSyntax("malformed argument"))
+    } else {
+        Ok(token)
+    }
+}
+
+/// The arguments of a CSS colour function.
+struct Arguments {
+    /// The colour components.
+    components: Vec<Component>,
+    /// The alpha after a `/`, or the fourth legacy argument.
+    alpha:      Option<Component>,
+    /// Whether the arguments were separated by commas.
+    legacy:     bool,
+}
+
+impl Arguments {
+    /// Splits the text between the parentheses into arguments.
+    fn parse(arguments: &str) -> Result<Self, ColorError> {
+        if arguments.contains(',') {
+            let mut components = arguments
+                .split(',')
+                .map(|token| single_token(token).and_then(Component::parse))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if components.iter().any(|c| matches!(c, Component::None)) {
+                return Err(ColorError::InvalidSyntax(
+                    "`none` is not allowed with commas",
+                ));
+            }
+
+            let alpha = match components.len() {
+                3 => None,
+                4 => components.pop(),
+                _ => return Err(ColorError::InvalidSyntax("expected 3 or 4 arguments")),
+            };
+
+            return Ok(Self {
+                components,
+                alpha,
+                legacy: true,
+            });
+        }
+
+        let (components, alpha) = match arguments.split_once('/') {
+            Some((components, alpha)) => (components, Some(single_token(alpha)?)),
+            None => (arguments, None),
+        };
+
+        Ok(Self {
+            components: components
+                .split_whitespace()
+                .map(Component::parse)
+                .collect::<Result<_, _>>()?,
+            alpha:      alpha.map(Component::parse).transpose()?,
+            legacy:     false,
+        })
+    }
+
+    /// Checks that there are exactly `N` components.
+    fn components<const N: usize>(self) -> Result<([Component; N], Option<Component>), ColorError> {
+        let components = self
+            .components
+            .try_into()
+            .map_err(|_| ColorError::InvalidSyntax("wrong number of arguments"))?;
+
+        Ok((components, self.alpha))
+    }
+}
+
+/// Clamps a value into a percentage.
+#[allow(clippy::cast_possible_truncation)]
+const fn percentage(value: f64) -> PercentageF32 {
+    PercentageF32::new_clamped(value as f32)
+}
+
+/// Wraps a value into a hue.
+#[allow(clippy::cast_possible_truncation)]
+fn hue(value: f64) -> CircleDegreesF32 {
+    CircleDegreesF32::new_wrapped(value as f32)
+}
+
+/// Creates a colour from a `0xRRGGBB` value and an alpha byte.
+fn rgb_bytes(rgb: u32, alpha: u8) -> ParsedColor {
+    ParsedColor {
+        model: AnyColor::Rgb(Color::from_hex(rgb)),
+        alpha: PercentageF32::from_percent_of_u8(alpha),
+    }
+}
+
+/// Converts components in a predefined RGB colour space into D65 XYZ.
+fn rgb_space_to_xyz(space: &RgbColourSpace, components: MatrixRow) -> Color<Xyz> {
+    let transfer_function = space.transfer_function();
+    let linear = components.map(|c| transfer_function.to_linear(c));
+    let xyz = RgbXyzConverter::calculate_matrices(space).rgb_to_xyz(&linear);
+
+    Color::new_xyz_adapted(&xyz, &space.white_point(), ChromaticAdaptation::Bradford)
+}
+
+/// Parses the arguments of `color()`.
+fn parse_color_function(arguments: &str) -> Result<(AnyColor, Option<Component>), ColorError> {
+    let (space, arguments) = arguments
+        .trim_start()
+        .split_once(char::is_whitespace)
+        .ok_or(ColorError::InvalidSyntax("missing colour space"))?;
+
+    let arguments = Arguments::parse(arguments)?;
+
+    if arguments.legacy {
+        return Err(ColorError::InvalidSyntax("color() does not allow commas"));
+    }
+
+    let ([first, second, third], alpha) = arguments.components::<3>()?;
+    let components = [first.resolve(1.)?, second.resolve(1.)?, third.resolve(1.)?];
+    let [x, y, z] = components;
+
+    let model = match space {
+        "srgb" => {
+            AnyColor::RgbFloat(Color::new_rgb_float(
+                percentage(x),
+                percentage(y),
+                percentage(z),
+            ))
+        },
+        "srgb-linear" => AnyColor::LinearRgb(Color::new_linear_rgb(x, y, z)),
+        "display-p3" => AnyColor::Xyz(rgb_space_to_xyz(&RgbColourSpace::DISPLAY_P3, components)),
+        "a98-rgb" => AnyColor::Xyz(rgb_space_to_xyz(&RgbColourSpace::ADOBE_RGB, components)),
+        "prophoto-rgb" => {
+            AnyColor::Xyz(rgb_space_to_xyz(&RgbColourSpace::PROPHOTO_RGB, components))
+        },
+        "rec2020" => AnyColor::Xyz(rgb_space_to_xyz(&RgbColourSpace::REC_2020, components)),
+        "xyz" | "xyz-d65" => AnyColor::Xyz(Color::new_xyz(x, y, z)),
+        "xyz-d50" => {
+            AnyColor::Xyz(Color::new_xyz_adapted(
+                &components,
+                &D50_STANDARD_ILLUMINANT,
+                ChromaticAdaptation::Bradford,
+            ))
+        },
+        _ => return Err(ColorError::InvalidSyntax("unknown colour space")),
+    };
+
+    Ok((model, alpha))
+}
+
+/// Parses the arguments of the function called `name`.
+fn parse_function(
+    function: &str,
+    arguments: &str,
+) -> Result<(AnyColor, Option<Component>), ColorError> {
+    if function == "color" {
+        return parse_color_function(arguments);
+    }
+
+    let arguments = Arguments::parse(arguments)?;
+
+    if arguments.legacy && !matches!(function, "rgb" | "rgba" | "hsl" | "hsla") {
+        return Err(ColorError::InvalidSyntax("function does not allow commas"));
+    }
+
+    if function == "device-cmyk" {
+        let ([cyan, magenta, yellow, key_black], alpha) = arguments.components::<4>()?;
+        let ink = |c: Component| c.resolve(1.).map(percentage);
+
+        let model = AnyColor::CmykFloat(Color::new_cmyk_float(
+            ink(cyan)?,
+            ink(magenta)?,
+            ink(yellow)?,
+            ink(key_black)?,
+        ));
+
+        return Ok((model, alpha));
+    }
+
+    let legacy = arguments.legacy;
+    let ([first, second, third], alpha) = arguments.components::<3>()?;
+
+    let model = match function {
+        "rgb" | "rgba" => {
+            if legacy
+                && (second.is_percentage() != first.is_percentage()
+                    || third.is_percentage() != first.is_percentage())
+            {
+                return Err(ColorError::InvalidSyntax(
+                    "cannot mix numbers and percentages with commas",
+                ));
+            }
+
+            let channel = |c: Component| c.resolve(255.).map(|c| percentage(c / 255.));
+
+            AnyColor::RgbFloat(Color::new_rgb_float(
+                channel(first)?,
+                channel(second)?,
+                channel(third)?,
+            ))
+        },
+        "hsl" | "hsla" => {
+            if legacy && !(second.is_percentage() && third.is_percentage()) {
+                return Err(ColorError::InvalidSyntax(
+                    "saturation and lightness must be percentages with commas",
+                ));
+            }
+
+            AnyColor::Hsl(Color::new_hsl(
+                hue(first.resolve_hue()?),
+                percentage(second.resolve(100.)? / 100.),
+                percentage(third.resolve(100.)? / 100.),
+            ))
+        },
+        "hwb" => {
+            let whiteness = second.resolve(100.)?.clamp(0., 100.) / 100.;
+            let blackness = third.resolve(100.)?.clamp(0., 100.) / 100.;
+
+            let (saturation, value) = if whiteness + blackness >= 1. {
+                (0., whiteness / (whiteness + blackness))
+            } else {
+                let value = 1. - blackness;
+
+                (1. - whiteness / value, value)
+            };
+
+            AnyColor::Hsv(Color::new_hsv(
+                hue(first.resolve_hue()?),
+                percentage(saturation),
+                percentage(value),
+            ))
+        },
+        "lab" => {
+            AnyColor::Lab(Color::new_lab_with_white(
+                first.resolve(100.)?,
+                second.resolve(125.)?,
+                third.resolve(125.)?,
+                D50_STANDARD_ILLUMINANT,
+            ))
+        },
+        "lch" => {
+            AnyColor::Lch(Color::new_lch_with_white(
+                first.resolve(100.)?,
+                second.resolve(150.)?,
+                third.resolve_hue()?,
+                D50_STANDARD_ILLUMINANT,
+            ))
+        },
+        "oklab" => {
+            AnyColor::Oklab(Color::new_oklab(
+                first.resolve(1.)?,
+                second.resolve(0.4)?,
+                third.resolve(0.4)?,
+            ))
+        },
+        "oklch" => {
+            AnyColor::Oklch(Color::new_oklch(
+                first.resolve(1.)?,
+                second.resolve(0.4)?,
+                third.resolve_hue()?,
+            ))
+        },
+        _ => return Err(ColorError::InvalidSyntax("unknown colour function")),
+    };
+
+    Ok((model, alpha))
+}
+
+impl ParsedColor {
+    /// Parses a CSS Color Level 4 colour: a hex colour, a named colour or one
+    /// of the `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`,
+    /// `oklch()` or `color()` functions. The Level 5 `device-cmyk()` function
+    /// is also accepted, so that CMYK colours survive a round trip.
+    ///
+    /// As in CSS, there must be no whitespace between a function's name and
+    /// its `(`, and the legacy comma-separated syntax does not allow `none` or
+    /// mixing numbers with percentages.
+    fn parse(input: &str) -> Result<Self, ColorError> {
+        let input = input.trim().to_ascii_lowercase();
+
+        if let Some(digits) = input.strip_prefix('#') {
+            return parse_hex_digits(digits).map(|(rgb, alpha)| rgb_bytes(rgb, alpha));
+        }
+
+        let Some((function, arguments)) = input
+            .strip_suffix(')')
+            .and_then(|input| input.split_once('('))
+        else {
+            return match input.as_str() {
+                "transparent" => Ok(rgb_bytes(0, 0)),
+                name => {
+                    named::lookup(name)
+                        .map(|rgb| rgb_bytes(rgb, u8::MAX))
+                        .ok_or(ColorError::InvalidSyntax("unknown colour name"))
+                },
+            };
+        };
+
+        let (model, alpha) = parse_function(function, arguments)?;
+
+        Ok(Self {
+            model,
+            alpha: alpha
+                .map_or(Ok(1.), |alpha| alpha.resolve(1.))
+                .map(percentage)?,
+        })
+    }
+}
+
+/// Parses a CSS colour, discarding any alpha channel. Colours outside of the
+/// sRGB gamut are clipped.
+impl FromStr for Color<Rgb> {
+    type Err = ColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParsedColor::parse(s).map(|parsed| Color::from(parsed.model).to_rgb())
+    }
+}
+
+/// Parses a CSS colour. Colours outside of the sRGB gamut are clipped.
+impl FromStr for Color<Alpha<Rgb>> {
+    type Err = ColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParsedColor::parse(s)
+            .map(|parsed| Color::from(parsed.model).to_rgb().with_alpha(parsed.alpha))
+    }
+}
+
+/// Parses a CSS colour into XYZ, which preserves colours outside of the sRGB
+/// gamut.
+impl FromStr for Color<Alpha<Xyz>> {
+    type Err = ColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParsedColor::parse(s)
+            .map(|parsed| Color::from(parsed.model).to_xyz().with_alpha(parsed.alpha))
+    }
+}
+
+/// Parses a CSS colour, keeping the model it was written in.
+///
+/// No model in this crate stores the `display-p3`, `a98-rgb`, `prophoto-rgb`,
+/// `rec2020` or `xyz-d50` spaces of `color()`, so those colours are converted
+/// to D65 XYZ and are written back as `color(xyz-d65 …)`.
+impl FromStr for Color<Alpha<AnyColor>> {
+    type Err = ColorError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParsedColor::parse(s).map(|parsed| Color::from(parsed.model).with_alpha(parsed.alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        error::ColorError,
+        models::{
+            Alpha,
+            AnyColor,
+            Rgb,
+            Xyz,
+            color_model::test_utils::assert_close,
+        },
+    };
+
+    fn rgba(input: &str) -> (u8, u8, u8, u8) {
+        let colour: Color<Alpha<Rgb>> = input
+            .parse()
+            .unwrap_or_else(|error| panic!("Failed to parse {input}: {error}"));
+
+        (
+            colour.red(),
+            colour.green(),
+            colour.blue(),
+            colour.alpha().to_percent_of_u8(),
+        )
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(rgba("#f80"), (0xFF, 0x88, 0x00, 0xFF));
+        assert_eq!(rgba("#f808"), (0xFF, 0x88, 0x00, 0x88));
+        assert_eq!(rgba("#12AB3c"), (0x12, 0xAB, 0x3C, 0xFF));
+        assert_eq!(rgba("#12ab3c80"), (0x12, 0xAB, 0x3C, 0x80));
+    }
+
+    #[test]
+    fn test_named() {
+        assert_eq!(rgba("RebeccaPurple"), (0x66, 0x33, 0x99, 0xFF));
+        assert_eq!(rgba(" red "), (0xFF, 0x00, 0x00, 0xFF));
+        assert_eq!(rgba("transparent"), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_rgb_functions() {
+        assert_eq!(rgba("rgb(255, 136, 0)"), (255, 136, 0, 255));
+        assert_eq!(rgba("rgba(255, 136, 0, 0.5)"), (255, 136, 0, 128));
+        assert_eq!(rgba("rgb(255 136 0 / 50%)"), (255, 136, 0, 128));
+        assert_eq!(rgba("rgb(100% 0% 50%)"), (255, 0, 128, 255));
+        assert_eq!(rgba("rgb(300 -20 none)"), (255, 0, 0, 255));
+    }
+
+    #[test]
+    fn test_cylindrical_functions() {
+        assert_eq!(rgba("hsl(120, 100%, 25%)"), (0, 128, 0, 255));
+        assert_eq!(rgba("hsl(0.5turn 100% 50% / 0.25)"), (0, 255, 255, 64));
+        assert_eq!(rgba("hsla(-120deg, 100%, 50%, 1)"), (0, 0, 255, 255));
+        assert_eq!(rgba("hwb(0 0% 0%)"), (255, 0, 0, 255));
+        assert_eq!(rgba("hwb(90 60% 60%)"), (128, 128, 128, 255));
+    }
+
+    #[test]
+    fn test_lab_functions() {
+        // Examples from CSS Color Level 4, all of which are #7d2329.
+        for input in [
+            "lab(29.2345% 39.3825 20.0664)",
+            "lch(29.2345% 44.2 27)",
+            "oklab(40.101% 0.1147 0.0453)",
+            "oklch(40.101% 0.12332 21.555)",
+        ] {
+            assert_eq!(rgba(input), (0x7D, 0x23, 0x29, 0xFF), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_color_function() {
+        assert_eq!(rgba("color(srgb 0.5 0 1)"), (128, 0, 255, 255));
+        assert_eq!(rgba("color(display-p3 1 0 0 / 0.5)"), (255, 0, 0, 128));
+        assert_eq!(rgba("color(srgb-linear 1 1 1)"), (255, 255, 255, 255));
+        assert_eq!(
+            rgba("color(xyz-d65 0.95047 1 1.08883)"),
+            (255, 255, 255, 255)
+        );
+        assert_eq!(
+            rgba("color(xyz-d50 0.96422 1 0.82521)"),
+            (255, 255, 255, 255)
+        );
+
+        // Display P3 red is outside of the sRGB gamut, which XYZ preserves.
+        let p3_red: Color<Alpha<Xyz>> = "color(display-p3 1 0 0)"
+            .parse()
+            .expect("Failed to parse Display P3 red");
+
+        assert_close(p3_red.x(), 0.486_6, 1e-3);
+    }
+
+    #[test]
+    fn test_device_cmyk() {
+        assert_eq!(rgba("device-cmyk(0 0.2 1 0.5)"), (128, 102, 0, 255));
+        assert_eq!(
+            rgba("device-cmyk(0% 20% 100% 50% / 50%)"),
+            (128, 102, 0, 128)
+        );
+    }
+
+    #[test]
+    fn test_model_is_preserved() {
+        let colour: Color<Alpha<AnyColor>> = "hwb(120deg 20% 30% / 0.5)"
+            .parse()
+            .expect("Failed to parse HWB");
+
+        assert!(matches!(
+            colour.without_alpha().into_model(),
+            AnyColor::Hsv(_)
+        ));
+
+        let colour: Color<Alpha<AnyColor>> = "color(srgb-linear 0.5 0.25 1.5)"
+            .parse()
+            .expect("Failed to parse linear sRGB");
+
+        assert!(matches!(
+            colour.without_alpha().into_model(),
+            AnyColor::LinearRgb(_)
+        ));
+        assert_eq!(colour.to_string(), "color(srgb-linear 0.5 0.25 1.5)");
+
+        // Wide-gamut spaces without a model of their own are kept as XYZ.
+        let colour: Color<Alpha<AnyColor>> = "color(display-p3 1 0 0)"
+            .parse()
+            .expect("Failed to parse Display P3");
+
+        assert!(matches!(
+            colour.without_alpha().into_model(),
+            AnyColor::Xyz(_)
+        ));
+        assert!(colour.to_string().starts_with("color(xyz-d65 "));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        for input in [
+            "",
+            "#12",
+            "#12345g",
+            "rgb(1 2)",
+            "rgb(1, 2 3)",
+            "rgb (1 2 3)",
+            "color (srgb 1 0 0)",
+            "rgb(10, 20%, 30)",
+            "rgba(10%, 20%, 30, 0.5)",
+            "rgb(none, 0, 0)",
+            "hsl(120, 100, 25%)",
+            "hwb(1, 2%, 3%)",
+            "hsl(10% 50% 50%)",
+            "rgb(1 2 3 / )",
+            "color(unknown 1 2 3)",
+            "notacolour",
+            "rgb(nan 0 0)",
+        ] {
+            assert!(
+                matches!(
+                    input.parse::<Color<Rgb>>(),
+                    Err(ColorError::InvalidSyntax(_))
+                ),
+                "{input} should have been rejected"
+            );
+        }
+    }
+}
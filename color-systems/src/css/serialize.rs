@@ -0,0 +1,309 @@
+use {
+    crate::{
+        color::Color,
+        models::color_model::ColorModel,
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+        Write,
+    },
+};
+
+/// A colour model that can be written as a CSS colour.
+pub trait ToCss {
+    /// Writes the colour using the options in `formatter`, including `alpha`
+    /// if it is given.
+    ///
+    /// # Errors
+    /// Returns any error from writing to `out`.
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result;
+}
+
+/// A single component of a CSS colour function.
+pub enum CssValue {
+    /// A keyword such as `none`.
+    Keyword(&'static str),
+    /// A plain number.
+    Number(f64),
+    /// A percentage, where `100` is 100%.
+    Percentage(f64),
+    /// An angle in degrees.
+    Degrees(f64),
+}
+
+/// Options for writing colours as CSS.
+#[derive(Clone, Copy, Debug)]
+pub struct CssFormatter {
+    /// Whether to use the comma-separated syntax where it exists.
+    legacy:        bool,
+    /// The maximum number of decimal places.
+    precision:     usize,
+    /// Whether hex digits are uppercase.
+    uppercase_hex: bool,
+    /// Whether to write sRGB colours as hex.
+    hex:           bool,
+}
+
+impl Default for CssFormatter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CssFormatter {
+    /// Creates a formatter using modern space-separated syntax, 4 decimal
+    /// places and lowercase hex, writing 8-bit RGB colours as hex.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            legacy:        false,
+            precision:     4,
+            uppercase_hex: false,
+            hex:           true,
+        }
+    }
+
+    /// Whether to use the legacy comma-separated syntax, such as
+    /// `rgba(26, 43, 60, 0.5)`, where the function supports it.
+    #[inline]
+    #[must_use]
+    pub const fn legacy(mut self, legacy: bool) -> Self {
+        self.legacy = legacy;
+        self
+    }
+
+    /// The maximum number of decimal places to write. Trailing zeros are
+    /// omitted.
+    #[inline]
+    #[must_use]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Whether hex colours use uppercase digits.
+    #[inline]
+    #[must_use]
+    pub const fn uppercase_hex(mut self, uppercase_hex: bool) -> Self {
+        self.uppercase_hex = uppercase_hex;
+        self
+    }
+
+    /// Whether 8-bit RGB colours are written as hex rather than `rgb()`.
+    #[inline]
+    #[must_use]
+    pub const fn hex(mut self, hex: bool) -> Self {
+        self.hex = hex;
+        self
+    }
+
+    /// Whether sRGB colours should be written as hex.
+    #[inline]
+    pub(crate) const fn uses_hex(&self) -> bool {
+        self.hex
+    }
+
+    /// Formats a colour as a CSS string.
+    #[inline]
+    pub fn format<C: ColorModel + ToCss>(&self, color: &Color<C>) -> String {
+        let mut out = String::new();
+
+        // Writing to a `String` cannot fail.
+        let _ = color.color.write_css(self, None, &mut out);
+
+        out
+    }
+
+    /// Writes `#rrggbb`, or `#rrggbbaa` if `alpha` is given.
+    pub(crate) fn write_hex(
+        &self,
+        out: &mut dyn Write,
+        [red, green, blue]: [u8; 3],
+        alpha: Option<PercentageF32>,
+    ) -> fmt::Result {
+        let bytes = [red, green, blue]
+            .into_iter()
+            .chain(alpha.map(PercentageF32::to_percent_of_u8));
+
+        out.write_char('#')?;
+
+        for byte in bytes {
+            if self.uppercase_hex {
+                write!(out, "{byte:02X}")?;
+            } else {
+                write!(out, "{byte:02x}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a CSS colour function such as `hsl(210deg 50% 40% / 0.5)`.
+    /// `supports_legacy` marks functions that also have a comma-separated
+    /// form.
+    pub(crate) fn write_function(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        components: &[CssValue],
+        alpha: Option<PercentageF32>,
+        supports_legacy: bool,
+    ) -> fmt::Result {
+        let legacy = self.legacy && supports_legacy;
+
+        out.write_str(name)?;
+
+        if legacy && alpha.is_some() {
+            out.write_char('a')?;
+        }
+
+        out.write_char('(')?;
+
+        for (index, component) in components.iter().enumerate() {
+            if index > 0 {
+                out.write_str(if legacy { ", " } else { " " })?;
+            }
+
+            match *component {
+                CssValue::Keyword(keyword) => out.write_str(keyword)?,
+                CssValue::Number(number) => self.write_number(out, number)?,
+                CssValue::Percentage(percentage) => {
+                    self.write_number(out, percentage)?;
+                    out.write_char('%')?;
+                },
+                CssValue::Degrees(degrees) => {
+                    self.write_number(out, degrees)?;
+
+                    if !legacy {
+                        out.write_str("deg")?;
+                    }
+                },
+            }
+        }
+
+        if let Some(alpha) = alpha {
+            out.write_str(if legacy { ", " } else { " / " })?;
+            self.write_number(out, f64::from(alpha.value()))?;
+        }
+
+        out.write_char(')')
+    }
+
+    /// Writes a number rounded to the configured precision, without trailing
+    /// zeros.
+    fn write_number(&self, out: &mut dyn Write, number: f64) -> fmt::Result {
+        let formatted = format!("{number:.precision$}", precision = self.precision);
+        let trimmed = if formatted.contains('.') {
+            formatted.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            &formatted
+        };
+
+        out.write_str(
+            if trimmed == "-0" {
+                "0"
+            } else {
+                trimmed
+            },
+        )
+    }
+}
+
+/// Writes the colour as CSS using the default [`CssFormatter`]. The precision
+/// can be overridden, such as with `{:.2}`.
+impl<C: ColorModel + ToCss> Display for Color<C> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut formatter = CssFormatter::new();
+
+        if let Some(precision) = f.precision() {
+            formatter = formatter.precision(precision);
+        }
+
+        self.color.write_css(&formatter, None, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::CssFormatter,
+        crate::{
+            circle_degrees_f32,
+            color::Color,
+            models::{
+                Alpha,
+                Rgb,
+            },
+            p32,
+        },
+    };
+
+    #[test]
+    fn test_modern_syntax() {
+        let hsl = Color::new_hsl(circle_degrees_f32!(210.), p32!(0.5), p32!(0.4));
+
+        assert_eq!(hsl.to_string(), "hsl(210deg 50% 40%)");
+        assert_eq!(Color::from_hex(0x1a_2b3c).to_string(), "#1a2b3c");
+        assert_eq!(
+            CssFormatter::new()
+                .hex(false)
+                .format(&Color::from_hex(0x1a_2b3c)),
+            "rgb(26 43 60)"
+        );
+        assert_eq!(
+            Color::new_rgb(255, 0, 0).to_hsv().to_string(),
+            "hwb(0deg 0% 0%)"
+        );
+        assert_eq!(
+            Color::new_oklch(0.5, 0.1, 120.).to_string(),
+            "oklch(0.5 0.1 120deg)"
+        );
+    }
+
+    #[test]
+    fn test_formatter_options() {
+        let colour = Color::from_hex(0x1a_2b3c).with_alpha(p32!(0.5));
+
+        assert_eq!(colour.to_string(), "#1a2b3c80");
+        assert_eq!(
+            CssFormatter::new().uppercase_hex(true).format(&colour),
+            "#1A2B3C80"
+        );
+        assert_eq!(
+            CssFormatter::new().hex(false).legacy(true).format(&colour),
+            "rgba(26, 43, 60, 0.5)"
+        );
+        assert_eq!(
+            CssFormatter::new().hex(false).format(&colour),
+            "rgb(26 43 60 / 0.5)"
+        );
+        assert_eq!(
+            format!("{:.2}", Color::new_oklch(0.123_456, 0.1, 120.)),
+            "oklch(0.12 0.1 120deg)"
+        );
+        assert_eq!(
+            format!("{}", Color::new_rgb(0, 0, 0).with_alpha(p32!(1.))),
+            "#000000"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for css in ["#1a2b3c", "#1a2b3c80", "#ffffff"] {
+            let colour: Color<Alpha<Rgb>> = css.parse().expect("Valid CSS was rejected");
+
+            assert_eq!(colour.to_string(), css);
+        }
+    }
+}
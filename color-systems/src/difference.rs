@@ -0,0 +1,239 @@
+//! Colour difference (delta E) metrics.
+
+use crate::{
+    advanced::MatrixRow,
+    color::Color,
+    models::color_model::ColorModel,
+};
+
+/// A colour difference metric, calculated between two CIELAB colours given as
+/// `[L*, a*, b*]` relative to D65.
+pub trait DeltaE {
+    /// The difference between the `reference` and `sample` colours.
+    fn delta_e(reference: &MatrixRow, sample: &MatrixRow) -> f64;
+}
+
+/// The CIE 1976 colour difference: Euclidean distance in CIELAB.
+pub struct Cie76;
+
+/// The CIE 1994 colour difference, using the graphic arts weightings.
+///
+/// This metric is not symmetric, so the order of the colours matters.
+pub struct Cie94;
+
+/// The CIEDE2000 colour difference.
+pub struct Ciede2000;
+
+impl DeltaE for Cie76 {
+    #[inline]
+    fn delta_e(reference: &MatrixRow, sample: &MatrixRow) -> f64 {
+        let [delta_l, delta_a, delta_b] = [0, 1, 2].map(|i| reference[i] - sample[i]);
+
+        delta_b
+            .mul_add(delta_b, delta_l.mul_add(delta_l, delta_a * delta_a))
+            .sqrt()
+    }
+}
+
+impl DeltaE for Cie94 {
+    fn delta_e(reference: &MatrixRow, sample: &MatrixRow) -> f64 {
+        const K_1: f64 = 0.045;
+        const K_2: f64 = 0.015;
+
+        let [l_1, a_1, b_1] = *reference;
+        let [l_2, a_2, b_2] = *sample;
+
+        let c_1 = a_1.hypot(b_1);
+        let c_2 = a_2.hypot(b_2);
+
+        let delta_l = l_1 - l_2;
+        let delta_c = c_1 - c_2;
+        let delta_a = a_1 - a_2;
+        let delta_b = b_1 - b_2;
+        let delta_h_squared = delta_c
+            .mul_add(-delta_c, delta_a.mul_add(delta_a, delta_b * delta_b))
+            .max(0.);
+
+        let s_c = K_1.mul_add(c_1, 1.);
+        let s_h = K_2.mul_add(c_1, 1.);
+
+        let chroma_term = delta_c / s_c;
+
+        chroma_term
+            .mul_add(
+                chroma_term,
+                delta_l.mul_add(delta_l, delta_h_squared / s_h.powi(2)),
+            )
+            .sqrt()
+    }
+}
+
+impl DeltaE for Ciede2000 {
+    fn delta_e(reference: &MatrixRow, sample: &MatrixRow) -> f64 {
+        const TWENTY_FIVE_TO_SEVENTH: f64 = 6_103_515_625.;
+
+        let [l_1, a_1, b_1] = *reference;
+        let [l_2, a_2, b_2] = *sample;
+
+        let c_bar_seventh = f64::midpoint(a_1.hypot(b_1), a_2.hypot(b_2)).powi(7);
+        let g = 0.5 * (1. - (c_bar_seventh / (c_bar_seventh + TWENTY_FIVE_TO_SEVENTH)).sqrt());
+
+        let a_1_prime = (1. + g) * a_1;
+        let a_2_prime = (1. + g) * a_2;
+
+        let c_1_prime = a_1_prime.hypot(b_1);
+        let c_2_prime = a_2_prime.hypot(b_2);
+
+        let hue = |b: f64, a_prime: f64| {
+            if b == 0. && a_prime == 0. {
+                0.
+            } else {
+                b.atan2(a_prime).to_degrees().rem_euclid(360.)
+            }
+        };
+
+        let h_1_prime = hue(b_1, a_1_prime);
+        let h_2_prime = hue(b_2, a_2_prime);
+
+        let chroma_product = c_1_prime * c_2_prime;
+        let hue_difference = h_2_prime - h_1_prime;
+
+        let delta_lightness = l_2 - l_1;
+        let delta_chroma = c_2_prime - c_1_prime;
+        let delta_hue_angle = if chroma_product == 0. {
+            0.
+        } else if hue_difference > 180. {
+            hue_difference - 360.
+        } else if hue_difference < -180. {
+            hue_difference + 360.
+        } else {
+            hue_difference
+        };
+        let delta_hue = 2. * chroma_product.sqrt() * (delta_hue_angle / 2.).to_radians().sin();
+
+        let l_bar_prime = f64::midpoint(l_1, l_2);
+        let c_bar_prime = f64::midpoint(c_1_prime, c_2_prime);
+        let h_bar_prime = if chroma_product == 0. {
+            h_1_prime + h_2_prime
+        } else if hue_difference.abs() <= 180. {
+            f64::midpoint(h_1_prime, h_2_prime)
+        } else if h_1_prime + h_2_prime < 360. {
+            (h_1_prime + h_2_prime + 360.) / 2.
+        } else {
+            (h_1_prime + h_2_prime - 360.) / 2.
+        };
+
+        let cos = |degrees: f64| degrees.to_radians().cos();
+
+        let t = 0.2f64.mul_add(
+            -cos(4f64.mul_add(h_bar_prime, -63.)),
+            0.32f64.mul_add(
+                cos(3f64.mul_add(h_bar_prime, 6.)),
+                0.24f64.mul_add(
+                    cos(2. * h_bar_prime),
+                    0.17f64.mul_add(-cos(h_bar_prime - 30.), 1.),
+                ),
+            ),
+        );
+
+        let delta_theta = 30. * (-((h_bar_prime - 275.) / 25.).powi(2)).exp();
+        let c_bar_prime_seventh = c_bar_prime.powi(7);
+        let r_c =
+            2. * (c_bar_prime_seventh / (c_bar_prime_seventh + TWENTY_FIVE_TO_SEVENTH)).sqrt();
+
+        let l_offset_squared = (l_bar_prime - 50.).powi(2);
+        let s_l = 0.015f64.mul_add(l_offset_squared / (20. + l_offset_squared).sqrt(), 1.);
+        let s_c = 0.045f64.mul_add(c_bar_prime, 1.);
+        let s_h = (0.015 * c_bar_prime).mul_add(t, 1.);
+        let r_t = -(2. * delta_theta).to_radians().sin() * r_c;
+
+        let lightness_term = delta_lightness / s_l;
+        let chroma_term = delta_chroma / s_c;
+        let hue_term = delta_hue / s_h;
+
+        (r_t * chroma_term)
+            .mul_add(
+                hue_term,
+                hue_term.mul_add(
+                    hue_term,
+                    lightness_term.mul_add(lightness_term, chroma_term * chroma_term),
+                ),
+            )
+            .sqrt()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Calculates the perceptual difference between this colour and another
+    /// using the metric `D`, converting both through CIELAB.
+    #[inline]
+    pub fn delta_e<D: DeltaE>(&self, other: &Color<impl ColorModel>) -> f64 {
+        D::delta_e(&self.lab_components(), &other.lab_components())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            Cie76,
+            Cie94,
+            Ciede2000,
+            DeltaE,
+        },
+        crate::{
+            color::Color,
+            models::color_model::test_utils::assert_close,
+        },
+    };
+
+    #[test]
+    fn test_cie76() {
+        assert_close(Cie76::delta_e(&[50., 0., 0.], &[50., 3., 4.]), 5., 1e-4);
+        assert_close(Cie76::delta_e(&[50., 0., 0.], &[60., 0., 0.]), 10., 1e-4);
+    }
+
+    #[test]
+    fn test_cie94() {
+        assert_close(Cie94::delta_e(&[50., 0., 0.], &[60., 0., 0.]), 10., 1e-4);
+        assert_close(
+            Cie94::delta_e(&[50., 2.6772, -79.7751], &[50., 0., -82.7485]),
+            1.3950,
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_ciede2000_reference_pairs() {
+        // Selected pairs from Sharma, Wu and Dalal (2005).
+        for (reference, sample, expected) in [
+            ([50., 2.6772, -79.7751], [50., 0., -82.7485], 2.0425),
+            ([50., 0., 0.], [50., -1., 2.], 2.3669),
+            ([50., 2.5, 0.], [73., 25., -18.], 27.1492),
+            ([50., 2.5, 0.], [50., 0., -2.5], 4.3065),
+            (
+                [60.2574, -34.0099, 36.2677],
+                [60.4626, -34.1751, 39.4387],
+                1.2644,
+            ),
+            (
+                [22.7233, 20.0904, -46.694],
+                [23.0331, 14.973, -42.5619],
+                2.0373,
+            ),
+            ([2.0776, 0.0795, -1.135], [0.9033, -0.0636, -0.5514], 0.9082),
+        ] {
+            assert_close(Ciede2000::delta_e(&reference, &sample), expected, 1e-4);
+            assert_close(Ciede2000::delta_e(&sample, &reference), expected, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_colour_difference_across_models() {
+        let rgb = Color::new_rgb(92, 17, 73);
+
+        assert_close(rgb.delta_e::<Ciede2000>(&rgb.to_xyz()), 0., 1e-4);
+        assert_close(rgb.delta_e::<Cie76>(&rgb.to_oklch()), 0., 1e-4);
+        assert!(rgb.delta_e::<Ciede2000>(&Color::new_rgb(92, 17, 74)) < 1.);
+    }
+}
@@ -0,0 +1,117 @@
+use std::{
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// An error produced when constructing or converting colours.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColorError {
+    /// A percentage was outside of the range `0..=1`, or was NaN.
+    PercentageOutOfRange(f32),
+    /// An angle was outside of the range `0..360`.
+    DegreesOutOfRange(f32),
+    /// A component was infinite or NaN.
+    NotFinite(f64),
+    /// A component could not be calculated while converting between models.
+    ComponentUnavailable(&'static str),
+    /// A string could not be parsed as a colour.
+    InvalidSyntax(&'static str),
+    /// A packed hex value had bits set above the 24 used for `0xRRGGBB`.
+    HexOutOfRange(u32),
+    /// A gradient had no stops, or stops that were not in increasing order.
+    InvalidGradient(&'static str),
+}
+
+impl Display for ColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PercentageOutOfRange(value) => {
+                write!(f, "percentage {value} is outside of the range 0..=1")
+            },
+            Self::DegreesOutOfRange(value) => {
+                write!(f, "angle {value} is outside of the range 0..360")
+            },
+            Self::NotFinite(value) => write!(f, "component {value} is not finite"),
+            Self::ComponentUnavailable(component) => {
+                write!(f, "{component} could not be calculated")
+            },
+            Self::InvalidSyntax(reason) => write!(f, "invalid colour syntax: {reason}"),
+            Self::HexOutOfRange(value) => {
+                write!(f, "hex value {value:#x} does not fit in 0xRRGGBB")
+            },
+            Self::InvalidGradient(reason) => write!(f, "invalid gradient: {reason}"),
+        }
+    }
+}
+
+impl Error for ColorError {}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::ColorError,
+        crate::{
+            advanced::{
+                ChromaticAdaptation,
+                D50_STANDARD_ILLUMINANT,
+            },
+            circle_degrees::CircleDegrees,
+            color::Color,
+            percentage_f32::PercentageF32,
+        },
+    };
+
+    #[test]
+    fn test_invalid_construction_is_rejected() {
+        assert_eq!(
+            Color::try_new_hsl(120., 1.5, 0.5).err(),
+            Some(ColorError::PercentageOutOfRange(1.5))
+        );
+        assert_eq!(
+            Color::try_new_hsv(360., 0.5, 0.5).err(),
+            Some(ColorError::DegreesOutOfRange(360.))
+        );
+        assert!(Color::try_new_cmyk_float(0., 0., f32::NAN, 0.).is_err());
+        assert_eq!(
+            Color::try_new_oklab(0.5, f64::INFINITY, 0.).err(),
+            Some(ColorError::NotFinite(f64::INFINITY))
+        );
+        assert_eq!(
+            Color::try_new_lab_with_white(50., 0., 0., [0.96, f64::INFINITY, 0.82]).err(),
+            Some(ColorError::NotFinite(f64::INFINITY))
+        );
+        assert!(Color::try_new_lch_with_white(50., f64::NAN, 0., D50_STANDARD_ILLUMINANT).is_err());
+        assert!(
+            Color::try_new_xyz_adapted(
+                &[0.5, 0.5, 0.5],
+                &[f64::INFINITY, 1., 1.],
+                ChromaticAdaptation::Bradford
+            )
+            .is_err()
+        );
+        assert!(Color::try_new_lab_with_white(50., 0., 0., D50_STANDARD_ILLUMINANT).is_ok());
+        assert!(CircleDegrees::try_from(-1).is_err());
+
+        let hsl = Color::try_new_hsl(120., 1., 0.25).expect("Valid HSL was rejected");
+
+        assert_eq!(
+            hsl.try_to_rgb().map(|c| (c.red(), c.green(), c.blue())),
+            Ok((0, 128, 0))
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let half = PercentageF32::new_or_panic(0.5);
+        let quarter = PercentageF32::new_or_panic(0.25);
+
+        assert_eq!(half.checked_add(quarter).map(|p| p.value()), Ok(0.75));
+        assert!(quarter.checked_sub(half).is_err());
+        assert!(half.checked_div(quarter).is_err());
+        assert!(half.checked_div(PercentageF32::MIN).is_err());
+    }
+}
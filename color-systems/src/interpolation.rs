@@ -0,0 +1,522 @@
+//! Interpolation between colours, and gradients with any number of stops.
+//!
+//! Colours are interpolated in a model chosen by a type parameter, which
+//! changes how the colours in between look. Mixing red and blue in
+//! [`RgbFloat`](crate::models::RgbFloat) passes through a dark purple, while
+//! [`Oklab`](crate::models::Oklab) keeps the lightness even and
+//! [`Hsl`](crate::models::Hsl) travels around the hue circle:
+//!
+//! ```
+//! use color_systems::{
+//!     interpolation::Gradient,
+//!     prelude::*,
+//! };
+//!
+//! let red = Color::new_rgb(255, 0, 0);
+//! let blue = Color::new_rgb(0, 0, 255);
+//!
+//! assert_eq!(
+//!     red.mix::<RgbFloat>(&blue, 0.5).to_rgb().to_hex_string(),
+//!     "#800080"
+//! );
+//! assert_eq!(
+//!     red.mix::<Hsl>(&blue, 0.5).to_rgb().to_hex_string(),
+//!     "#ff00ff"
+//! );
+//!
+//! let heatmap = Gradient::<Oklab>::new([blue, Color::new_rgb(255, 255, 0), red])
+//!     .expect("Gradient had no stops");
+//!
+//! assert_eq!(heatmap.sample(5).len(), 5);
+//! ```
+//!
+//! Blending light, as a camera or a translucent surface would, happens in
+//! [`LinearRgb`](crate::models::LinearRgb).
+
+use crate::{
+    circle_degrees_f32::CircleDegreesF32,
+    color::Color,
+    error::ColorError,
+    models::color_model::{
+        ColorModel,
+        FromColorModel,
+    },
+    percentage_f32::PercentageF32,
+};
+
+/// A model that colours can be interpolated in.
+pub trait Interpolate: FromColorModel + Clone {
+    /// Interpolates between this colour and `other`, where a `t` of `0` gives
+    /// this colour and `1` gives `other`. Models with a hue interpolate it
+    /// along the arc chosen by `hue`.
+    #[must_use]
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self;
+}
+
+/// Which way around the hue circle to interpolate, as in CSS `color-mix()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HueInterpolation {
+    /// The shortest arc between the hues.
+    #[default]
+    Shorter,
+    /// The longest arc between the hues, which goes all the way around the
+    /// circle if they are equal.
+    Longer,
+    /// The arc where the hue increases.
+    Increasing,
+    /// The arc where the hue decreases.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// The signed difference in degrees from `from` to `to` along the arc.
+    pub(crate) fn difference(self, from: f64, to: f64) -> f64 {
+        let difference = (to - from).rem_euclid(360.);
+
+        match self {
+            Self::Shorter if difference > 180. => difference - 360.,
+            Self::Longer if difference == 0. => 360.,
+            Self::Longer if difference < 180. => difference - 360.,
+            Self::Decreasing if difference > 0. => difference - 360.,
+            Self::Shorter | Self::Longer | Self::Increasing | Self::Decreasing => difference,
+        }
+    }
+
+    /// Interpolates between two hues in degrees, returning a hue in `0..360`.
+    ///
+    /// A hue of `None` is powerless, such as the hue of a grey, and takes the
+    /// other hue so that mixing with white or black does not shift the hue.
+    pub(crate) fn interpolate(self, from: Option<f64>, to: Option<f64>, t: f32) -> f64 {
+        match (from, to) {
+            (Some(from), Some(to)) => {
+                self.difference(from, to)
+                    .mul_add(f64::from(t), from)
+                    .rem_euclid(360.)
+            },
+            (Some(hue), None) | (None, Some(hue)) => hue,
+            (None, None) => 0.,
+        }
+    }
+
+    /// Interpolates between two hues, as [`Self::interpolate`].
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn interpolate_degrees(
+        self,
+        from: Option<CircleDegreesF32>,
+        to: Option<CircleDegreesF32>,
+        t: f32,
+    ) -> CircleDegreesF32 {
+        let degrees = |hue: CircleDegreesF32| f64::from(hue.value());
+        let hue = self.interpolate(from.map(degrees), to.map(degrees), t);
+
+        CircleDegreesF32::new_wrapped(hue as f32)
+    }
+}
+
+/// Linearly interpolates between two components.
+pub(crate) fn lerp(from: f64, to: f64, t: f32) -> f64 {
+    (to - from).mul_add(f64::from(t), from)
+}
+
+/// Linearly interpolates between two percentages.
+pub(crate) fn lerp_percentage(from: PercentageF32, to: PercentageF32, t: f32) -> PercentageF32 {
+    PercentageF32::new_clamped((to.value() - from.value()).mul_add(t, from.value()))
+}
+
+/// An easing function, which maps evenly spaced positions in `0..=1` to the
+/// positions that a [`Gradient`] is sampled at.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    /// Evenly spaced, `t`.
+    #[default]
+    Linear,
+    /// Slow at the start, `t²`.
+    EaseIn,
+    /// Slow at the end, `1 - (1 - t)²`.
+    EaseOut,
+    /// Slow at both ends, `t²(3 - 2t)`.
+    EaseInOut,
+    /// Any function, whose results are clamped to `0..=1`.
+    Custom(fn(f32) -> f32),
+}
+
+impl Easing {
+    /// Applies the easing function to a position in `0..=1`.
+    #[inline]
+    #[must_use]
+    pub fn ease(self, t: f32) -> f32 {
+        let t = PercentageF32::new_clamped(t).value();
+
+        let eased = match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => (1. - t).mul_add(-(1. - t), 1.),
+            Self::EaseInOut => t * t * 2f32.mul_add(-t, 3.),
+            Self::Custom(function) => function(t),
+        };
+
+        PercentageF32::new_clamped(eased).value()
+    }
+}
+
+/// A gradient through any number of colours, interpolated in the model `M`.
+#[derive(Clone, Debug)]
+pub struct Gradient<M: Interpolate> {
+    /// The positions, in `0..=1` and in increasing order, and colours of the
+    /// stops.
+    stops: Vec<(f32, Color<M>)>,
+    /// Which way around the hue circle to interpolate.
+    hue:   HueInterpolation,
+}
+
+impl<M: Interpolate> Gradient<M> {
+    /// Creates a gradient with the colours spaced evenly from `0` to `1`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::InvalidGradient`] if there are no colours, or
+    /// any error from converting them to `M`.
+    pub fn new<C: ColorModel>(
+        colours: impl IntoIterator<Item = Color<C>>,
+    ) -> Result<Self, ColorError> {
+        let colours: Vec<_> = colours.into_iter().collect();
+        let last = colours.len().saturating_sub(1).max(1);
+
+        #[allow(clippy::cast_precision_loss)]
+        Self::with_stops(
+            colours
+                .into_iter()
+                .enumerate()
+                .map(|(i, colour)| (i as f32 / last as f32, colour)),
+        )
+    }
+
+    /// Creates a gradient from stops at positions in `0..=1`. Two stops at
+    /// the same position make a hard edge between their colours.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if a position is outside
+    /// of `0..=1`, [`ColorError::InvalidGradient`] if there are no stops or
+    /// the positions are not in increasing order, or any error from
+    /// converting the colours to `M`.
+    pub fn with_stops<C: ColorModel>(
+        stops: impl IntoIterator<Item = (f32, Color<C>)>,
+    ) -> Result<Self, ColorError> {
+        let stops = stops
+            .into_iter()
+            .map(|(position, colour)| {
+                Ok((
+                    PercentageF32::try_new(position)?.value(),
+                    colour.try_convert()?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ColorError>>()?;
+
+        if stops.is_empty() {
+            return Err(ColorError::InvalidGradient(
+                "a gradient needs at least one stop",
+            ));
+        }
+
+        if stops.windows(2).any(|pair| pair[1].0 < pair[0].0) {
+            return Err(ColorError::InvalidGradient(
+                "stops are not in increasing order",
+            ));
+        }
+
+        Ok(Self {
+            stops,
+            hue: HueInterpolation::default(),
+        })
+    }
+
+    /// Which way around the hue circle to interpolate between stops, for
+    /// models with a hue. Defaults to [`HueInterpolation::Shorter`].
+    #[inline]
+    #[must_use]
+    pub const fn hue_interpolation(mut self, hue: HueInterpolation) -> Self {
+        self.hue = hue;
+        self
+    }
+
+    /// The positions and colours of the stops.
+    #[inline]
+    #[must_use]
+    pub fn stops(&self) -> &[(f32, Color<M>)] {
+        &self.stops
+    }
+
+    /// The colour at a position in `0..=1`. Positions before the first stop
+    /// or after the last take the colour of that stop.
+    #[must_use]
+    pub fn at(&self, position: f32) -> Color<M> {
+        let position = PercentageF32::new_clamped(position).value();
+        let next = self
+            .stops
+            .partition_point(|(stop, _)| *stop <= position)
+            .clamp(1, self.stops.len().saturating_sub(1).max(1));
+
+        match self.stops.get(next - 1..=next) {
+            Some([(start, from), (end, to)]) if end > start => {
+                let t = PercentageF32::new_clamped((position - start) / (end - start)).value();
+
+                Color::new(from.color.interpolate(&to.color, t, self.hue))
+            },
+            Some([(start, from), (_, to)]) => {
+                if position < *start {
+                    from.clone()
+                } else {
+                    to.clone()
+                }
+            },
+            _ => self.stops[0].1.clone(),
+        }
+    }
+
+    /// `count` colours spaced evenly from the start to the end of the
+    /// gradient.
+    #[inline]
+    #[must_use]
+    pub fn sample(&self, count: usize) -> Vec<Color<M>> {
+        self.sample_eased(count, Easing::Linear)
+    }
+
+    /// `count` colours from the start to the end of the gradient, with the
+    /// positions spaced by `easing`.
+    #[must_use]
+    pub fn sample_eased(&self, count: usize, easing: Easing) -> Vec<Color<M>> {
+        let last = count.saturating_sub(1).max(1);
+
+        #[allow(clippy::cast_precision_loss)]
+        (0..count)
+            .map(|i| self.at(easing.ease(i as f32 / last as f32)))
+            .collect()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Mixes this colour with `other` in the model `M`, where a `t` of `0`
+    /// gives this colour and `1` gives `other`. `t` is clamped to `0..=1`,
+    /// and hues are interpolated along the shortest arc.
+    ///
+    /// # Panics
+    /// Panics if either colour cannot be converted to `M`; see
+    /// [`Self::convert`].
+    #[inline]
+    pub fn mix<M: Interpolate>(&self, other: &Color<impl ColorModel>, t: f32) -> Color<M> {
+        self.mix_with_hue(other, t, HueInterpolation::Shorter)
+    }
+
+    /// Mixes this colour with `other` in the model `M`, interpolating hues
+    /// along the arc chosen by `hue`.
+    ///
+    /// # Panics
+    /// Panics if either colour cannot be converted to `M`; see
+    /// [`Self::convert`].
+    #[inline]
+    pub fn mix_with_hue<M: Interpolate>(
+        &self,
+        other: &Color<impl ColorModel>,
+        t: f32,
+        hue: HueInterpolation,
+    ) -> Color<M> {
+        let t = PercentageF32::new_clamped(t).value();
+
+        Color::new(
+            self.convert::<M>()
+                .color
+                .interpolate(&other.convert::<M>().color, t, hue),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            Easing,
+            Gradient,
+            HueInterpolation,
+        },
+        crate::{
+            color::Color,
+            error::ColorError,
+            models::{
+                Alpha,
+                Hsl,
+                Lab,
+                Lch,
+                Oklab,
+                Oklch,
+                RgbFloat,
+                color_model::{
+                    ColorModel,
+                    test_utils::assert_close,
+                },
+            },
+        },
+    };
+
+    fn hex<C: ColorModel>(colour: &Color<C>) -> String {
+        colour.to_rgb().to_hex_string()
+    }
+
+    #[test]
+    fn test_hue_interpolation() {
+        for (hue, expected) in [
+            (HueInterpolation::Shorter, 10.),
+            (HueInterpolation::Longer, 190.),
+            (HueInterpolation::Increasing, 10.),
+            (HueInterpolation::Decreasing, 190.),
+        ] {
+            let actual = hue.interpolate(Some(340.), Some(40.), 0.5);
+
+            assert_close(actual, expected, 1e-9);
+        }
+
+        assert_close(
+            HueInterpolation::Longer.interpolate(Some(90.), Some(90.), 0.5),
+            270.,
+            1e-9,
+        );
+        assert_close(
+            HueInterpolation::Shorter.interpolate(None, Some(90.), 0.5),
+            90.,
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn test_mix() {
+        let red = Color::new_rgb(255, 0, 0);
+        let blue = Color::new_rgb(0, 0, 255);
+
+        assert_eq!(hex(&red.mix::<RgbFloat>(&blue, 0.)), "#ff0000");
+        assert_eq!(hex(&red.mix::<RgbFloat>(&blue, 1.)), "#0000ff");
+        assert_eq!(hex(&red.mix::<RgbFloat>(&blue, 2.)), "#0000ff");
+        assert_eq!(hex(&red.mix::<RgbFloat>(&blue, 0.5)), "#800080");
+        assert_eq!(hex(&red.mix::<Hsl>(&blue, 0.5)), "#ff00ff");
+        assert_eq!(
+            hex(&red.mix_with_hue::<Hsl>(&blue, 0.5, HueInterpolation::Longer)),
+            "#00ff00"
+        );
+
+        for mixed in [
+            hex(&red.mix::<Lab>(&blue, 0.5)),
+            hex(&red.mix::<Lch>(&blue, 0.5)),
+            hex(&red.mix::<Oklab>(&blue, 0.5)),
+            hex(&red.mix::<Oklch>(&blue, 0.5)),
+        ] {
+            assert_ne!(mixed, "#800080");
+        }
+    }
+
+    #[test]
+    fn test_mix_alpha() {
+        let opaque = Color::new_rgba(255, 0, 0, 255);
+        let transparent = Color::new_rgba(0, 0, 255, 0);
+        let mixed = opaque.mix::<Alpha<RgbFloat>>(&transparent, 0.25);
+
+        assert_close(f64::from(mixed.alpha().value()), 0.75, 1e-6);
+        assert_eq!(hex(&mixed), "#bf0040");
+    }
+
+    #[test]
+    fn test_mix_with_grey_keeps_hue() {
+        let red = Color::new_rgb(255, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+
+        assert_close(
+            f64::from(red.mix::<Hsl>(&white, 0.5).hue().value()),
+            0.,
+            1e-4,
+        );
+        assert_close(red.mix::<Lch>(&white, 0.5).hue_lch(), red.hue_lch(), 0.1);
+        assert_close(
+            red.mix::<Lch>(&Color::new_rgb(0, 0, 0), 0.5).hue_lch(),
+            red.hue_lch(),
+            0.1,
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let gradient = Gradient::<RgbFloat>::new([
+            Color::new_rgb(0, 0, 0),
+            Color::new_rgb(255, 255, 255),
+            Color::new_rgb(255, 0, 0),
+        ])
+        .expect("Gradient was rejected");
+
+        let samples: Vec<_> = gradient.sample(5).iter().map(hex).collect();
+
+        assert_eq!(
+            samples,
+            ["#000000", "#808080", "#ffffff", "#ff8080", "#ff0000"]
+        );
+        assert_eq!(hex(&gradient.at(-1.)), "#000000");
+        assert_eq!(hex(&gradient.at(2.)), "#ff0000");
+        assert!(gradient.sample(0).is_empty());
+        assert_eq!(
+            gradient.sample(1).iter().map(hex).collect::<Vec<_>>(),
+            ["#000000"]
+        );
+    }
+
+    #[test]
+    fn test_gradient_stops() {
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+
+        let hard_edge =
+            Gradient::<Oklab>::with_stops([(0., black), (0.5, black), (0.5, white), (1., white)])
+                .expect("Gradient was rejected");
+
+        assert_eq!(hex(&hard_edge.at(0.49)), "#000000");
+        assert_eq!(hex(&hard_edge.at(0.5)), "#ffffff");
+
+        let single = Gradient::<Oklab>::with_stops([(0.5, white)]).expect("Gradient was rejected");
+
+        assert_eq!(hex(&single.at(0.)), "#ffffff");
+
+        assert_eq!(
+            Gradient::<Oklab>::with_stops([(0.5, black), (0.25, white)]).err(),
+            Some(ColorError::InvalidGradient(
+                "stops are not in increasing order"
+            ))
+        );
+        assert_eq!(
+            Gradient::<Oklab>::with_stops([(1.5, black)]).err(),
+            Some(ColorError::PercentageOutOfRange(1.5))
+        );
+        assert!(Gradient::<Oklab>::new(Vec::<Color<Oklab>>::new()).is_err());
+    }
+
+    #[test]
+    fn test_easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_close(f64::from(easing.ease(0.)), 0., 1e-6);
+            assert_close(f64::from(easing.ease(1.)), 1., 1e-6);
+        }
+
+        assert_close(f64::from(Easing::EaseIn.ease(0.5)), 0.25, 1e-6);
+        assert_close(f64::from(Easing::EaseOut.ease(0.5)), 0.75, 1e-6);
+        assert_close(f64::from(Easing::EaseInOut.ease(0.5)), 0.5, 1e-6);
+        assert_close(f64::from(Easing::Custom(|t| t * 2.).ease(0.75)), 1., 1e-6);
+
+        let gradient =
+            Gradient::<RgbFloat>::new([Color::new_rgb(0, 0, 0), Color::new_rgb(255, 255, 255)])
+                .expect("Gradient was rejected");
+        let eased: Vec<_> = gradient
+            .sample_eased(3, Easing::EaseIn)
+            .iter()
+            .map(hex)
+            .collect();
+
+        assert_eq!(eased, ["#000000", "#404040", "#ffffff"]);
+    }
+}
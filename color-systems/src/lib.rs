@@ -1,5 +1,67 @@
-// mod advanced;
+//! Colour models and the conversions between them.
+//!
+//! Every colour is a [`Color`] wrapping a [`ColorModel`], such as
+//! `Color<Rgb>` or `Color<Oklch>`. Any colour can read the components of, or
+//! convert into, any other model:
+//!
+//! ```
+//! use color_systems::prelude::*;
+//!
+//! let colour = Color::new_rgb(26, 43, 60);
+//! let hsl = colour.to_hsl();
+//!
+//! assert_eq!(hsl.to_string(), "hsl(210deg 39.5349% 16.8627%)");
+//! assert_eq!(hsl.to_rgb().to_hex_string(), "#1a2b3c");
+//! ```
+//!
+//! Generic code can convert into a model chosen by a type parameter with
+//! [`Color::convert`], or between the models in this crate with `From`.
+//!
+//! The model of a colour read at runtime, such as from CSS, can be kept with
+//! [`AnyColor`]. [`ColorModel`], [`ToCss`](css::ToCss),
+//! [`DeltaE`](difference::DeltaE) and
+//! [`Interpolate`](interpolation::Interpolate) are not sealed, so new models,
+//! CSS serializations, difference metrics and interpolation can be added by
+//! other crates.
+
+pub mod advanced;
+/// Whole degrees around a circle.
 mod circle_degrees;
+/// Fractional degrees around a circle.
+mod circle_degrees_f32;
+/// The colour wrapper that every model is used through.
 mod color;
-mod models;
+pub mod contrast;
+pub mod css;
+pub mod difference;
+/// The error type.
+mod error;
+pub mod interpolation;
+pub mod models;
+/// Percentages as `f32`.
 mod percentage_f32;
+pub mod prelude;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategies;
+
+pub use {
+    circle_degrees::CircleDegrees,
+    circle_degrees_f32::CircleDegreesF32,
+    color::Color,
+    error::ColorError,
+    models::{
+        AnyColor,
+        color_model::{
+            ColorModel,
+            FromColorModel,
+            OpaqueColorModel,
+        },
+    },
+    percentage_f32::{
+        CheckedPercentage,
+        PercentageF32,
+        SaturatingPercentage,
+    },
+};
@@ -0,0 +1,301 @@
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        css::{
+            CssFormatter,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp_percentage,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            rgb::{
+                Rgb,
+                parse_hex_digits,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// Adds an alpha (opacity) channel to any colour model.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alpha<C: ColorModel> {
+    /// The colour without an alpha.
+    color: C,
+    /// The opacity, where `0` is fully transparent.
+    alpha: PercentageF32,
+}
+
+impl<T: ColorModel> ColorModel for Alpha<T> {
+    #[inline]
+    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
+        &self,
+    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
+        self.color.select_cmyk::<C, M, Y, K>()
+    }
+
+    #[inline]
+    fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        self.color.select_cmyk_float::<C, M, Y, K>()
+    }
+
+    #[inline]
+    fn select_rgb<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (Option<u8>, Option<u8>, Option<u8>) {
+        self.color.select_rgb::<R, G, B>()
+    }
+
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        self.color.select_rgb_float::<R, G, B>()
+    }
+
+    #[inline]
+    fn select_hsv<const H: bool, const S: bool, const V: bool>(
+        &self,
+    ) -> (
+        Option<CircleDegreesF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        self.color.select_hsv::<H, S, V>()
+    }
+
+    #[inline]
+    fn select_hsl<const H: bool, const S: bool, const L: bool>(
+        &self,
+    ) -> (
+        Option<CircleDegreesF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        self.color.select_hsl::<H, S, L>()
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        self.color.select_xyz::<X, Y, Z>()
+    }
+
+    #[inline]
+    fn alpha(&self) -> PercentageF32 {
+        self.alpha
+    }
+}
+
+/// The alpha channel is only written when the colour is not fully opaque.
+impl<T: ColorModel + ToCss> ToCss for Alpha<T> {
+    #[inline]
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        _alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let alpha = (self.alpha.value() < 1.).then_some(self.alpha);
+
+        self.color.write_css(formatter, alpha, out)
+    }
+}
+
+/// The alpha is interpolated linearly alongside the colour, without
+/// premultiplying it.
+impl<T: Interpolate + OpaqueColorModel> Interpolate for Alpha<T> {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self {
+        Self {
+            color: self.color.interpolate(&other.color, t, hue),
+            alpha: lerp_percentage(self.alpha, other.alpha, t),
+        }
+    }
+}
+
+/// The alpha of the source colour is kept.
+impl<T: FromColorModel + OpaqueColorModel> FromColorModel for Alpha<T> {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        Ok(T::try_from_color(color)?.with_alpha(color.alpha()))
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// The opacity of the colour, where `0` is fully transparent.
+    #[inline]
+    pub fn alpha(&self) -> PercentageF32 {
+        self.color.alpha()
+    }
+}
+
+impl<C: OpaqueColorModel> Color<C> {
+    /// Adds an alpha channel to this colour.
+    #[inline]
+    pub fn with_alpha(self, alpha: PercentageF32) -> Color<Alpha<C>> {
+        Color {
+            color: Alpha {
+                color: self.color,
+                alpha,
+            },
+        }
+    }
+}
+
+impl<C: ColorModel> Color<Alpha<C>> {
+    /// Replaces the alpha channel of this colour, rather than adding a second
+    /// one.
+    #[inline]
+    #[must_use]
+    pub const fn with_alpha(mut self, alpha: PercentageF32) -> Self {
+        self.color.alpha = alpha;
+        self
+    }
+
+    /// Replaces the alpha channel of this colour.
+    #[inline]
+    pub const fn set_alpha(&mut self, alpha: PercentageF32) {
+        self.color.alpha = alpha;
+    }
+
+    /// Removes the alpha channel, leaving the opaque colour.
+    #[inline]
+    pub fn without_alpha(self) -> Color<C> {
+        Color {
+            color: self.color.color,
+        }
+    }
+}
+
+impl Color<Alpha<Rgb>> {
+    /// Creates a new colour from a 32-bit `0xRRGGBBAA` value.
+    #[inline]
+    #[must_use]
+    pub fn from_hex_rgba(from: u32) -> Self {
+        Color::from_hex(from >> u8::BITS)
+            .with_alpha(PercentageF32::from_percent_of_u8(from.to_be_bytes()[3]))
+    }
+
+    /// Creates a new colour from a 32-bit `0xAARRGGBB` value.
+    #[inline]
+    #[must_use]
+    pub fn from_hex_argb(from: u32) -> Self {
+        Color::from_hex(from & 0x00FF_FFFF)
+            .with_alpha(PercentageF32::from_percent_of_u8(from.to_be_bytes()[0]))
+    }
+
+    /// Parses a hex colour such as `#1a2b3c80`, `#abcd` or `1a2b3c`. Colours
+    /// without an alpha are fully opaque.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::InvalidSyntax`] if the string is not 3, 4, 6 or 8
+    /// hex digits.
+    #[inline]
+    pub fn from_hex_rgba_str(from: &str) -> Result<Self, ColorError> {
+        let (rgb, alpha) = parse_hex_digits(from)?;
+
+        Ok(Color::from_hex(rgb).with_alpha(PercentageF32::from_percent_of_u8(alpha)))
+    }
+
+    /// Creates a new colour from 8-bit RGBA components.
+    #[inline]
+    #[must_use]
+    pub fn new_rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Color::new_rgb(red, green, blue).with_alpha(PercentageF32::from_percent_of_u8(alpha))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        models::{
+            Alpha,
+            Rgb,
+            color_model::test_utils::assert_close,
+        },
+        p32,
+    };
+
+    #[test]
+    fn test_alpha_defaults_to_opaque() {
+        assert_eq!(Color::new_rgb(12, 34, 56).alpha().to_percent_of_u8(), 255);
+    }
+
+    #[test]
+    fn test_from_hex() {
+        for colour in [
+            Color::from_hex_rgba(0x1122_3344),
+            Color::from_hex_argb(0x4411_2233),
+            Color::new_rgba(0x11, 0x22, 0x33, 0x44),
+            Color::from_hex_rgba_str("#11223344").expect("Valid hex was rejected"),
+            Color::from_hex_rgba_str("1234").expect("Valid hex was rejected"),
+        ] {
+            assert_eq!(
+                (
+                    colour.red(),
+                    colour.green(),
+                    colour.blue(),
+                    colour.alpha().to_percent_of_u8()
+                ),
+                (0x11, 0x22, 0x33, 0x44)
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_alpha_replaces_alpha() {
+        let colour: Color<Alpha<Rgb>> = Color::from_hex(0x1a_2b3c)
+            .with_alpha(p32!(0.5))
+            .with_alpha(p32!(0.25));
+
+        assert_eq!(colour.alpha().to_percent_of_u8(), 64);
+        assert_eq!(colour.to_string(), "#1a2b3c40");
+
+        let colour: Color<Alpha<Rgb>> = colour.convert();
+
+        assert_eq!(colour.alpha().to_percent_of_u8(), 64);
+        assert_eq!(colour.with_alpha(p32!(1.)).to_string(), "#1a2b3c");
+    }
+
+    #[test]
+    fn test_alpha_survives_model_delegation() {
+        let mut colour = Color::new_rgb(255, 0, 0).to_hsl().with_alpha(p32!(0.5));
+
+        assert_close(f64::from(colour.hue().value()), 0., 1e-4);
+        assert_close(f64::from(colour.alpha().value()), 0.5, 1e-6);
+
+        colour.set_alpha(p32!(0.25));
+
+        assert_close(f64::from(colour.alpha().value()), 0.25, 1e-6);
+        assert_eq!(colour.without_alpha().alpha().to_percent_of_u8(), 255);
+    }
+}
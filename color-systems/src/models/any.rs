@@ -0,0 +1,236 @@
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        css::{
+            CssFormatter,
+            ToCss,
+        },
+        models::{
+            Hsl,
+            Hsv,
+            Lab,
+            Lch,
+            LinearRgb,
+            Oklab,
+            Oklch,
+            Rgb,
+            RgbFloat,
+            Xyz,
+            cmyk::Cmyk,
+            cmyk_float::CmykFloat,
+            color_model::{
+                ColorModel,
+                OpaqueColorModel,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// A colour whose model is only known at runtime, such as one parsed from user
+/// input.
+///
+/// `Color<AnyColor>` has every accessor and conversion that the statically
+/// typed colours have, and the model can be matched on with
+/// [`Color::model`] or [`Color::into_model`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnyColor {
+    /// An 8-bit sRGB colour.
+    Rgb(Color<Rgb>),
+    /// An sRGB colour with percentage components.
+    RgbFloat(Color<RgbFloat>),
+    /// A linear-light sRGB colour.
+    LinearRgb(Color<LinearRgb>),
+    /// An 8-bit CMYK colour.
+    Cmyk(Color<Cmyk>),
+    /// A CMYK colour with percentage components.
+    CmykFloat(Color<CmykFloat>),
+    /// An HSL colour.
+    Hsl(Color<Hsl>),
+    /// An HSV colour, which also represents CSS `hwb()`.
+    Hsv(Color<Hsv>),
+    /// A CIE XYZ colour.
+    Xyz(Color<Xyz>),
+    /// A CIELAB colour.
+    Lab(Color<Lab>),
+    /// A CIELCh(ab) colour.
+    Lch(Color<Lch>),
+    /// An Oklab colour.
+    Oklab(Color<Oklab>),
+    /// An Oklch colour.
+    Oklch(Color<Oklch>),
+}
+
+/// Runs `$body` with `$model` bound to the model inside whichever variant
+/// `$self` is.
+macro_rules! dispatch {
+    ($self:expr, $model:ident => $body:expr) => {
+        match $self {
+            AnyColor::Rgb(Color { color: $model }) => $body,
+            AnyColor::RgbFloat(Color { color: $model }) => $body,
+            AnyColor::LinearRgb(Color { color: $model }) => $body,
+            AnyColor::Cmyk(Color { color: $model }) => $body,
+            AnyColor::CmykFloat(Color { color: $model }) => $body,
+            AnyColor::Hsl(Color { color: $model }) => $body,
+            AnyColor::Hsv(Color { color: $model }) => $body,
+            AnyColor::Xyz(Color { color: $model }) => $body,
+            AnyColor::Lab(Color { color: $model }) => $body,
+            AnyColor::Lch(Color { color: $model }) => $body,
+            AnyColor::Oklab(Color { color: $model }) => $body,
+            AnyColor::Oklch(Color { color: $model }) => $body,
+        }
+    };
+}
+
+impl ColorModel for AnyColor {
+    #[inline]
+    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
+        &self,
+    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
+        dispatch!(self, model => model.select_cmyk::<C, M, Y, K>())
+    }
+
+    #[inline]
+    fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        dispatch!(self, model => model.select_cmyk_float::<C, M, Y, K>())
+    }
+
+    #[inline]
+    fn select_rgb<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (Option<u8>, Option<u8>, Option<u8>) {
+        dispatch!(self, model => model.select_rgb::<R, G, B>())
+    }
+
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        dispatch!(self, model => model.select_rgb_float::<R, G, B>())
+    }
+
+    #[inline]
+    fn select_hsv<const H: bool, const S: bool, const V: bool>(
+        &self,
+    ) -> (
+        Option<CircleDegreesF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        dispatch!(self, model => model.select_hsv::<H, S, V>())
+    }
+
+    #[inline]
+    fn select_hsl<const H: bool, const S: bool, const L: bool>(
+        &self,
+    ) -> (
+        Option<CircleDegreesF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        dispatch!(self, model => model.select_hsl::<H, S, L>())
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        dispatch!(self, model => model.select_xyz::<X, Y, Z>())
+    }
+}
+
+impl OpaqueColorModel for AnyColor {}
+
+impl ToCss for AnyColor {
+    #[inline]
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        dispatch!(self, model => model.write_css(formatter, alpha, out))
+    }
+}
+
+/// Implements the conversions from each statically typed colour.
+macro_rules! impl_from_color {
+    ($($variant:ident),* $(,)?) => {
+        $(
+            impl From<Color<$variant>> for AnyColor {
+                #[inline]
+                fn from(color: Color<$variant>) -> Self {
+                    Self::$variant(color)
+                }
+            }
+
+            impl From<Color<$variant>> for Color<AnyColor> {
+                #[inline]
+                fn from(color: Color<$variant>) -> Self {
+                    Self {
+                        color: AnyColor::$variant(color),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_color!(
+    Rgb, RgbFloat, LinearRgb, Cmyk, CmykFloat, Hsl, Hsv, Xyz, Lab, Lch, Oklab, Oklch
+);
+
+impl From<AnyColor> for Color<AnyColor> {
+    #[inline]
+    fn from(color: AnyColor) -> Self {
+        Self { color }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::AnyColor,
+        crate::{
+            circle_degrees_f32,
+            color::Color,
+            models::color_model::test_utils::assert_close,
+            p32,
+        },
+    };
+
+    #[test]
+    fn test_runtime_model() {
+        let colours: [Color<AnyColor>; 3] = [
+            Color::new_rgb(255, 0, 0).into(),
+            Color::new_hsl(circle_degrees_f32!(0.), p32!(1.), p32!(0.5)).into(),
+            Color::new_oklch(0.627_955, 0.257_683, 29.233_885).into(),
+        ];
+
+        for colour in &colours {
+            assert_eq!((colour.red(), colour.green(), colour.blue()), (255, 0, 0));
+            assert_close(f64::from(colour.to_hsl().hue().value()), 0., 0.1);
+        }
+
+        assert!(matches!(colours[1].model(), AnyColor::Hsl(_)));
+        assert_eq!(colours[1].to_string(), "hsl(0deg 100% 50%)");
+        assert_eq!(colours[0].to_string(), "#ff0000");
+    }
+}
@@ -1,20 +1,39 @@
-use crate::{
-    circle_degrees::CircleDegrees,
-    color::Color,
-    models::{
-        color_model::ColorModel,
-        shared::{
-            cmyk_float_to_rgb_float,
-            rgb_float_to_hsvl,
+use {
+    crate::{
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::cmyk_float_to_rgb_float,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
     },
-    percentage_f32::PercentageF32,
 };
 
+/// 8-bit CMYK, where each component is from `0` to `255`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cmyk {
+    /// Cyan ink.
     cyan:      u8,
+    /// Magenta ink.
     magenta:   u8,
+    /// Yellow ink.
     yellow:    u8,
+    /// Black ink.
     key_black: u8,
 }
 
@@ -48,19 +67,6 @@ impl ColorModel for Cmyk {
         )
     }
 
-    #[inline]
-    fn select_rgb<const R: bool, const G: bool, const B: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>) {
-        let (r, g, b) = self.select_rgb_float::<R, G, B>();
-
-        (
-            r.map(PercentageF32::to_percent_of_u8),
-            g.map(PercentageF32::to_percent_of_u8),
-            b.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
         &self,
     ) -> (
@@ -68,62 +74,65 @@ impl ColorModel for Cmyk {
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
-        let (c, m, y, k) = self.select_cmyk_float::<R, G, B, true>();
-
-        cmyk_float_to_rgb_float::<R, G, B>(
-            c.expect("Cyan was not returned"),
-            m.expect("Magenta was not returned"),
-            y.expect("Yellow was not returned"),
-            k.expect("Key/black was not returned"),
-        )
+        match self.select_cmyk_float::<true, true, true, true>() {
+            (Some(c), Some(m), Some(y), Some(k)) => cmyk_float_to_rgb_float::<R, G, B>(c, m, y, k),
+            _ => (None, None, None),
+        }
     }
+}
 
-    fn select_hsv<const H: bool, const S: bool, const V: bool>(
-        &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
+impl OpaqueColorModel for Cmyk {}
 
-        rgb_float_to_hsvl::<H, S, V, false>(
-            r.expect("Red value was not returned"),
-            g.expect("Green value was not returned"),
-            b.expect("Blue value was not returned"),
+impl ToCss for Cmyk {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "device-cmyk",
+            &[self.cyan, self.magenta, self.yellow, self.key_black]
+                .map(|c| CssValue::Percentage(f64::from(c) / f64::from(u8::MAX) * 100.)),
+            alpha,
+            false,
         )
     }
+}
 
-    fn select_hsl<const H: bool, const S: bool, const L: bool>(
-        &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_hsvl::<H, S, L, true>(
-            r.expect("Red value was not returned"),
-            g.expect("Green value was not returned"),
-            b.expect("Blue value was not returned"),
-        )
+impl FromColorModel for Cmyk {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_cmyk()
     }
 }
 
 impl<C: ColorModel> Color<C> {
+    /// Converts the colour to 8-bit CMYK.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_cmyk`].
     #[inline]
     pub fn to_cmyk(&self) -> Color<Cmyk> {
-        let (c, m, y, k) = self.color.select_cmyk::<true, true, true, true>();
+        self.try_to_cmyk().expect("CMYK values were not returned")
+    }
 
-        Color::new_cmyk(
-            c.expect("Cyan not returned"),
-            m.expect("Magenta not returned"),
-            y.expect("Yellow not returned"),
-            k.expect("Key/black not returned"),
-        )
+    /// Converts the colour to 8-bit CMYK.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_cmyk(&self) -> Result<Color<Cmyk>, ColorError> {
+        match self.color.select_cmyk::<true, true, true, true>() {
+            (Some(c), Some(m), Some(y), Some(k)) => Ok(Color::new_cmyk(c, m, y, k)),
+            _ => Err(ColorError::ComponentUnavailable("CMYK")),
+        }
     }
 
+    /// Converts the colour to 8-bit CMYK, consuming it.
     #[inline]
     pub fn into_cmyk(self) -> Color<Cmyk> {
         self.to_cmyk()
@@ -131,7 +140,9 @@ impl<C: ColorModel> Color<C> {
 }
 
 impl Color<Cmyk> {
+    /// Creates a colour from 8-bit CMYK components.
     #[inline]
+    #[must_use]
     pub const fn new_cmyk(cyan: u8, magenta: u8, yellow: u8, key_black: u8) -> Self {
         Self {
             color: Cmyk {
@@ -146,22 +157,21 @@ impl Color<Cmyk> {
 
 #[cfg(test)]
 mod tests {
-    use {
-        super::super::color_model::test_utils::TestColour,
-        crate::{
-            circle_degrees,
-            models::{
-                cmyk::Cmyk,
-                color_model::test_utils::{
-                    colour_model_tests,
-                    GenerateColour,
-                },
+    use crate::{
+        color::Color,
+        models::{
+            cmyk::Cmyk,
+            color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                colour_model_tests,
             },
-            p32,
         },
     };
 
     impl GenerateColour<Cmyk> for TestColour {
+        const TOLERANCE: f64 = 1.;
+
         fn generate(&self) -> Cmyk {
             Cmyk {
                 cyan:      self.cmyk.0,
@@ -170,17 +180,42 @@ mod tests {
                 key_black: self.cmyk.3,
             }
         }
+
+        fn components(colour: &Cmyk) -> Vec<f64> {
+            [colour.cyan, colour.magenta, colour.yellow, colour.key_black]
+                .map(f64::from)
+                .to_vec()
+        }
     }
 
-    colour_model_tests!(TestColour {
-        cmyk:       (0, 0, 0, 0),
-        cmyk_float: (p32!(0.), p32!(0.), p32!(0.), p32!(0.)),
-        hsl:        (circle_degrees!(0), p32!(0.), p32!(0.)),
-        hsv:        (circle_degrees!(0), p32!(0.), p32!(0.)),
-        rgb:        (0, 0, 0),
-        rgb_float:  (p32!(0.), p32!(0.), p32!(0.)),
-    });
+    colour_model_tests!(Cmyk);
 
     #[test]
-    pub fn test() {}
+    fn test_black_and_edge_colours() {
+        for ((r, g, b), cmyk) in [
+            ((0, 0, 0), (0, 0, 0, 255)),
+            ((255, 255, 255), (0, 0, 0, 0)),
+            ((255, 0, 0), (0, 255, 255, 0)),
+            ((1, 0, 0), (0, 255, 255, 254)),
+        ] {
+            let colour = Color::new_rgb(r, g, b).to_cmyk();
+
+            assert_eq!(
+                (
+                    colour.cyan(),
+                    colour.magenta(),
+                    colour.yellow(),
+                    colour.key_black()
+                ),
+                cmyk
+            );
+
+            let round_trip = colour.to_rgb();
+
+            assert_eq!(
+                (round_trip.red(), round_trip.green(), round_trip.blue()),
+                (r, g, b)
+            );
+        }
+    }
 }
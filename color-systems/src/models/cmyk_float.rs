@@ -1,20 +1,37 @@
 use {
-    super::color_model::ColorModel,
+    super::color_model::{
+        ColorModel,
+        FromColorModel,
+        OpaqueColorModel,
+    },
     crate::{
-        circle_degrees::CircleDegrees,
         color::Color,
-        models::shared::{
-            cmyk_float_to_rgb_float,
-            rgb_float_to_hsvl,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        models::shared::cmyk_float_to_rgb_float,
         percentage_f32::PercentageF32,
     },
+    std::fmt::{
+        self,
+        Write,
+    },
 };
 
+/// CMYK, with each component stored as a percentage.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CmykFloat {
+    /// Cyan ink.
     cyan:      PercentageF32,
+    /// Magenta ink.
     magenta:   PercentageF32,
+    /// Yellow ink.
     yellow:    PercentageF32,
+    /// Black ink.
     key_black: PercentageF32,
 }
 
@@ -48,18 +65,6 @@ impl ColorModel for CmykFloat {
         )
     }
 
-    fn select_rgb<const R: bool, const G: bool, const B: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>) {
-        let (r, g, b) = self.select_rgb_float::<R, G, B>();
-
-        (
-            r.map(PercentageF32::to_percent_of_u8),
-            g.map(PercentageF32::to_percent_of_u8),
-            b.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
         &self,
     ) -> (
@@ -69,53 +74,61 @@ impl ColorModel for CmykFloat {
     ) {
         cmyk_float_to_rgb_float::<R, G, B>(self.cyan, self.magenta, self.yellow, self.key_black)
     }
+}
 
-    fn select_hsv<const H: bool, const S: bool, const V: bool>(
-        &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
+impl OpaqueColorModel for CmykFloat {}
 
-        rgb_float_to_hsvl::<H, S, V, false>(
-            r.expect("Red was not returned"),
-            g.expect("Green was not returned"),
-            b.expect("Blue was not returned"),
+impl ToCss for CmykFloat {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "device-cmyk",
+            &[self.cyan, self.magenta, self.yellow, self.key_black]
+                .map(|c| CssValue::Percentage(f64::from(c.value()) * 100.)),
+            alpha,
+            false,
         )
     }
+}
 
-    fn select_hsl<const H: bool, const S: bool, const L: bool>(
-        &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_hsvl::<H, S, L, true>(
-            r.expect("Red was not returned"),
-            g.expect("Green was not returned"),
-            b.expect("Blue was not returned"),
-        )
+impl FromColorModel for CmykFloat {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_cmyk_float()
     }
 }
 
 impl<C: ColorModel> Color<C> {
+    /// Converts the colour to CMYK percentages.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_cmyk_float`].
     #[inline]
     pub fn to_cmyk_float(&self) -> Color<CmykFloat> {
-        let (c, m, y, k) = self.color.select_cmyk_float::<true, true, true, true>();
+        self.try_to_cmyk_float()
+            .expect("CMYK values were not returned")
+    }
 
-        Color::new_cmyk_float(
-            c.expect("Cyan not returned"),
-            m.expect("Magenta not returned"),
-            y.expect("Yellow not returned"),
-            k.expect("Key/black not returned"),
-        )
+    /// Converts the colour to CMYK percentages.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_cmyk_float(&self) -> Result<Color<CmykFloat>, ColorError> {
+        match self.color.select_cmyk_float::<true, true, true, true>() {
+            (Some(c), Some(m), Some(y), Some(k)) => Ok(Color::new_cmyk_float(c, m, y, k)),
+            _ => Err(ColorError::ComponentUnavailable("CMYK")),
+        }
     }
 
+    /// Converts the colour to CMYK percentages, consuming it.
     #[inline]
     pub fn into_cmyk_float(self) -> Color<CmykFloat> {
         self.to_cmyk_float()
@@ -123,7 +136,9 @@ impl<C: ColorModel> Color<C> {
 }
 
 impl Color<CmykFloat> {
+    /// Creates a colour from CMYK percentages.
     #[inline]
+    #[must_use]
     pub const fn new_cmyk_float(
         cyan: PercentageF32,
         magenta: PercentageF32,
@@ -139,4 +154,58 @@ impl Color<CmykFloat> {
             },
         }
     }
+
+    /// Creates a new colour from CMYK components in the range `0..=1`, failing
+    /// if any are out of range.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if a component is outside
+    /// of `0..=1`.
+    #[inline]
+    pub fn try_new_cmyk_float(
+        cyan: f32,
+        magenta: f32,
+        yellow: f32,
+        key_black: f32,
+    ) -> Result<Self, ColorError> {
+        Ok(Self::new_cmyk_float(
+            PercentageF32::try_new(cyan)?,
+            PercentageF32::try_new(magenta)?,
+            PercentageF32::try_new(yellow)?,
+            PercentageF32::try_new(key_black)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::CmykFloat,
+        crate::models::color_model::test_utils::{
+            GenerateColour,
+            TestColour,
+            colour_model_tests,
+        },
+    };
+
+    impl GenerateColour<CmykFloat> for TestColour {
+        const TOLERANCE: f64 = 0.005;
+
+        fn generate(&self) -> CmykFloat {
+            CmykFloat {
+                cyan:      self.cmyk_float.0,
+                magenta:   self.cmyk_float.1,
+                yellow:    self.cmyk_float.2,
+                key_black: self.cmyk_float.3,
+            }
+        }
+
+        fn components(colour: &CmykFloat) -> Vec<f64> {
+            [colour.cyan, colour.magenta, colour.yellow, colour.key_black]
+                .map(|c| f64::from(c.value()))
+                .to_vec()
+        }
+    }
+
+    colour_model_tests!(CmykFloat);
 }
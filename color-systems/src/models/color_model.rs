@@ -1,13 +1,83 @@
+//! The traits implemented by colour models.
+//!
+//! [`ColorModel`] is implemented by every colour model, [`FromColorModel`] by
+//! those that colours can be converted into, and [`OpaqueColorModel`] by those
+//! without an alpha channel.
+
 use crate::{
-    circle_degrees::CircleDegrees,
+    circle_degrees_f32::CircleDegreesF32,
+    color::Color,
+    error::ColorError,
+    models::shared::{
+        get_rgb_using_float,
+        rgb_float_to_cmyk_float,
+        rgb_float_to_hsvl,
+        rgb_float_to_xyz,
+    },
     percentage_f32::PercentageF32,
 };
 
+/// A way of storing a colour, such as RGB or HSL.
+///
+/// Each `select_*` method calculates the components of the colour in another
+/// model. The const parameters choose which components are needed, and only
+/// those are returned as `Some`, so models can skip work that is not needed.
+/// `None` is also returned for components that the model cannot provide.
+///
+/// This trait is not sealed: implementing it for a new model gives
+/// `Color<YourModel>` every accessor and conversion in this crate. Only
+/// [`Self::select_rgb_float`] is required, as every other method defaults to
+/// converting from sRGB. Models that can calculate another model more directly
+/// or more precisely, such as XYZ for CIELAB, should override its method too.
+///
+/// ```
+/// use color_systems::prelude::*;
+///
+/// /// A single white LED, which can only show shades of grey.
+/// struct WhiteLed {
+///     brightness: PercentageF32,
+/// }
+///
+/// impl ColorModel for WhiteLed {
+///     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+///         &self,
+///     ) -> (
+///         Option<PercentageF32>,
+///         Option<PercentageF32>,
+///         Option<PercentageF32>,
+///     ) {
+///         let level = self.brightness;
+///
+///         (R.then_some(level), G.then_some(level), B.then_some(level))
+///     }
+/// }
+///
+/// let led = Color::new(WhiteLed {
+///     brightness: p32!(0.5),
+/// });
+///
+/// assert_eq!(led.to_rgb().to_hex_string(), "#808080");
+/// assert_eq!(led.key_black(), 128);
+/// assert!((led.luminosity().value() - 0.5).abs() < 1e-6);
+/// ```
 pub trait ColorModel {
+    /// Selects 8-bit CMYK components.
+    #[inline]
     fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
         &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>);
+    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
+        let (c, m, y, k) = self.select_cmyk_float::<C, M, Y, K>();
+
+        (
+            c.map(PercentageF32::to_percent_of_u8),
+            m.map(PercentageF32::to_percent_of_u8),
+            y.map(PercentageF32::to_percent_of_u8),
+            k.map(PercentageF32::to_percent_of_u8),
+        )
+    }
 
+    /// Selects CMYK components as percentages.
+    #[inline]
     fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
         &self,
     ) -> (
@@ -15,12 +85,23 @@ pub trait ColorModel {
         Option<PercentageF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
-    );
+    ) {
+        match self.select_rgb_float::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => rgb_float_to_cmyk_float::<C, M, Y, K>(r, g, b),
+            _ => (None, None, None, None),
+        }
+    }
 
+    /// Selects 8-bit sRGB components.
+    #[inline]
     fn select_rgb<const R: bool, const G: bool, const B: bool>(
         &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>);
+    ) -> (Option<u8>, Option<u8>, Option<u8>) {
+        get_rgb_using_float::<R, G, B, Self>(self)
+    }
 
+    /// Selects sRGB components as percentages. Every other method defaults to
+    /// converting from these.
     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
         &self,
     ) -> (
@@ -29,154 +110,401 @@ pub trait ColorModel {
         Option<PercentageF32>,
     );
 
+    /// Selects the hue, saturation and value of the sRGB colour.
+    #[inline]
     fn select_hsv<const H: bool, const S: bool, const V: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
-    );
+    ) {
+        match self.select_rgb_float::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => rgb_float_to_hsvl::<H, S, V, false>(r, g, b),
+            _ => (None, None, None),
+        }
+    }
 
+    /// Selects the hue, saturation and luminosity of the sRGB colour.
+    #[inline]
     fn select_hsl<const H: bool, const S: bool, const L: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
-    );
+    ) {
+        match self.select_rgb_float::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => rgb_float_to_hsvl::<H, S, L, true>(r, g, b),
+            _ => (None, None, None),
+        }
+    }
+
+    /// Selects CIE XYZ tristimulus values, relative to the D65 white point
+    /// with a luminance (`Y`) of `1`.
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        match self.select_rgb_float::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => rgb_float_to_xyz::<X, Y, Z>(r, g, b),
+            _ => (None, None, None),
+        }
+    }
+
+    /// The opacity of the colour, where `0` is fully transparent. Models
+    /// without an alpha channel are fully opaque.
+    #[inline]
+    fn alpha(&self) -> PercentageF32 {
+        PercentageF32::MAX
+    }
+}
+
+/// A model that any colour can be converted into.
+///
+/// This drives [`Color::convert`] and [`Color::try_convert`], so generic code
+/// can convert into a model chosen by a type parameter. Every model in this
+/// crate other than [`AnyColor`](crate::AnyColor) implements it, and a new
+/// model usually only needs to build itself from the components of
+/// [`ColorModel::select_rgb_float`] or [`ColorModel::select_xyz`].
+pub trait FromColorModel: ColorModel + Sized {
+    /// Converts `color` into this model.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model of `color`
+    /// cannot provide the components this model is calculated from.
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError>;
 }
 
+/// A model without an alpha channel, which [`Alpha`](crate::models::Alpha) can
+/// add one to.
+///
+/// Every model in this crate other than `Alpha` implements it, so that adding
+/// an alpha to a colour that already has one replaces it instead of nesting
+/// the wrappers. A new model should implement it too so that it can be given
+/// an alpha with [`Color::with_alpha`].
+pub trait OpaqueColorModel: ColorModel {}
+
 #[cfg(test)]
 #[macro_use]
-pub mod test_utils {
+pub(crate) mod test_utils {
     use crate::{
-        circle_degrees::CircleDegrees,
-        models::color_model::ColorModel,
+        circle_degrees_f32,
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        models::{
+            Rgb,
+            color_model::{
+                ColorModel,
+                FromColorModel,
+            },
+        },
+        p32,
         percentage_f32::PercentageF32,
     };
 
+    /// Builds a model from the reference values of a [`TestColour`], and reads
+    /// its components back for comparison.
     pub trait GenerateColour<C: ColorModel> {
+        /// How far apart the components of two models can be and still be
+        /// considered equal.
+        const TOLERANCE: f64;
+
         fn generate(&self) -> C;
+
+        fn components(colour: &C) -> Vec<f64>;
     }
 
+    /// A colour with its values in every model, calculated independently of
+    /// this crate.
     pub struct TestColour {
         pub cmyk:       (u8, u8, u8, u8),
         pub cmyk_float: (PercentageF32, PercentageF32, PercentageF32, PercentageF32),
-        pub hsl:        (CircleDegrees, PercentageF32, PercentageF32),
-        pub hsv:        (CircleDegrees, PercentageF32, PercentageF32),
+        pub hsl:        (CircleDegreesF32, PercentageF32, PercentageF32),
+        pub hsv:        (CircleDegreesF32, PercentageF32, PercentageF32),
         pub rgb:        (u8, u8, u8),
         pub rgb_float:  (PercentageF32, PercentageF32, PercentageF32),
+        pub linear_rgb: (f64, f64, f64),
+        pub xyz:        (f64, f64, f64),
+        pub lab:        (f64, f64, f64),
+        pub lch:        (f64, f64, f64),
+        pub oklab:      (f64, f64, f64),
+        pub oklch:      (f64, f64, f64),
     }
 
-    macro_rules! colour_model_tests {
-        ($test_colour:expr) => {};
-    }
-
-    macro_rules! assert_rgb_color {
-        ($selector:expr, $value:expr, $expected:expr) => {
-            if $selector {
-                assert_eq!(
-                    $value.unwrap_or_else(|| {
-                        panic!("Returned {} value was None", stringify!($selector));
-                    }),
-                    $expected,
-                    "Expected {} value of {}",
-                    stringify!($selector),
-                    $expected
-                )
-            } else {
-                assert_eq!(
-                    $value,
-                    None,
-                    "Expected {} value of None",
-                    stringify!($selector)
-                )
-            }
-        };
+    pub fn reference_colours() -> [TestColour; 2] {
+        [
+            TestColour {
+                cmyk:       (0, 48, 74, 94),
+                cmyk_float: (p32!(0.), p32!(0.186_34), p32!(0.291_93), p32!(0.368_63)),
+                hsl:        (circle_degrees_f32!(21.702), p32!(0.2), p32!(0.539_22)),
+                hsv:        (circle_degrees_f32!(21.702), p32!(0.291_93), p32!(0.631_37)),
+                rgb:        (161, 131, 114),
+                rgb_float:  (p32!(0.631_37), p32!(0.513_73), p32!(0.447_06)),
+                linear_rgb: (0.356_4, 0.226_97, 0.168_27),
+                xyz:        (0.258_52, 0.250_26, 0.193_85),
+                lab:        (57.1, 8.87, 13.523),
+                lch:        (57.1, 16.172, 56.74),
+                oklab:      (0.633_53, 0.027_97, 0.034_67),
+                oklch:      (0.633_53, 0.044_55, 51.097),
+            },
+            TestColour {
+                cmyk:       (0, 208, 53, 163),
+                cmyk_float: (p32!(0.), p32!(0.815_22), p32!(0.206_52), p32!(0.639_22)),
+                hsl:        (circle_degrees_f32!(315.2), p32!(0.688_07), p32!(0.213_73)),
+                hsv:        (circle_degrees_f32!(315.2), p32!(0.815_22), p32!(0.360_78)),
+                rgb:        (92, 17, 73),
+                rgb_float:  (p32!(0.360_78), p32!(0.066_67), p32!(0.286_27)),
+                linear_rgb: (0.107_02, 0.005_61, 0.066_63),
+                xyz:        (0.058_17, 0.031_58, 0.066_05),
+                lab:        (20.665, 39., -15.369),
+                lch:        (20.665, 41.919, 338.492),
+                oklab:      (0.332_51, 0.116_57, -0.041_5),
+                oklch:      (0.332_51, 0.123_74, 340.404),
+            },
+        ]
     }
 
-    macro_rules! rgb_tests {
-        (
-            $none_color:expr,
-            $r_only_color:expr,
-            $g_only_color:expr,
-            $b_only_color:expr,
-            $rg_only_color:expr,
-            $rb_only_color:expr,
-            $gb_only_color:expr,
-            $all_color:expr
-        ) => {
-            use $crate::color::color_model::test_utils::assert_rgb_color;
-
-            #[inline(always)]
-            fn rgb_asserts<const R: bool, const G: bool, const B: bool>(color: Rgb) {
-                let (r, g, b) = color.select_rgb::<R, G, B>();
-
-                assert_rgb_color!(R, r, color.red);
-                assert_rgb_color!(G, g, color.green);
-                assert_rgb_color!(B, b, color.blue);
-            }
+    /// Checks that `actual` is within `tolerance` of `expected`.
+    #[track_caller]
+    pub fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "Expected {expected}, got {actual}"
+        );
+    }
 
-            #[test]
-            fn test_select_rgb_none() {
-                let color = $none_color;
+    #[track_caller]
+    fn assert_percentages(actual: &[PercentageF32], expected: &[PercentageF32]) {
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert_close(f64::from(actual.value()), f64::from(expected.value()), 0.01);
+        }
+    }
 
-                rgb_asserts::<false, false, false>(color);
-            }
+    fn assert_bytes(name: &str, actual: &[u8], expected: &[u8], tolerance: u8) {
+        for (&actual, &expected) in actual.iter().zip(expected) {
+            assert!(
+                actual.abs_diff(expected) <= tolerance,
+                "Expected {name} of {expected}, got {actual}"
+            );
+        }
+    }
 
-            #[test]
-            fn test_select_rgb_r_only() {
-                let color = $r_only_color;
+    /// Checks that the model built from `reference` provides the reference
+    /// values of every other model, and that converting the reference RGB into
+    /// the model gives the model's own reference values.
+    pub fn assert_reference<C: FromColorModel>(reference: &TestColour)
+    where
+        TestColour: GenerateColour<C>,
+    {
+        let colour = Color::new(reference.generate());
 
-                rgb_asserts::<true, false, false>(color);
-            }
+        assert_bytes(
+            "RGB",
+            &[colour.red(), colour.green(), colour.blue()],
+            &<[u8; 3]>::from(reference.rgb),
+            1,
+        );
+        assert_bytes(
+            "CMYK",
+            &[
+                colour.cyan(),
+                colour.magenta(),
+                colour.yellow(),
+                colour.key_black(),
+            ],
+            &<[u8; 4]>::from(reference.cmyk),
+            1,
+        );
+        assert_percentages(
+            &[
+                colour.red_float(),
+                colour.green_float(),
+                colour.blue_float(),
+            ],
+            &<[PercentageF32; 3]>::from(reference.rgb_float),
+        );
 
-            #[test]
-            fn test_select_rgb_g_only() {
-                let color = $g_only_color;
+        let (Some(cyan), Some(magenta), Some(yellow), Some(key_black)) =
+            colour.model().select_cmyk_float::<true, true, true, true>()
+        else {
+            panic!("CMYK values were not returned");
+        };
+        assert_percentages(
+            &[cyan, magenta, yellow, key_black],
+            &<[PercentageF32; 4]>::from(reference.cmyk_float),
+        );
 
-                rgb_asserts::<false, true, false>(color);
-            }
-            #[test]
-            fn test_select_rgb_b_only() {
-                let color = $b_only_color;
+        let (hue, saturation, luminosity) = reference.hsl;
+        assert_close(f64::from(colour.hue().value()), f64::from(hue.value()), 1.);
+        assert_percentages(
+            &[colour.saturation_hsl(), colour.luminosity()],
+            &[saturation, luminosity],
+        );
 
-                rgb_asserts::<false, false, true>(color);
-            }
+        let (_, saturation, value) = reference.hsv;
+        assert_percentages(
+            &[colour.saturation_hsv(), colour.hsv_value()],
+            &[saturation, value],
+        );
 
-            #[test]
-            fn test_select_rgb_rg_only() {
-                let color = $rg_only_color;
+        for (actual, expected) in [
+            (colour.x(), reference.xyz.0),
+            (colour.y(), reference.xyz.1),
+            (colour.z(), reference.xyz.2),
+        ] {
+            assert_close(actual, expected, 0.01);
+        }
+
+        let (red, green, blue) = reference.rgb;
+        let converted = Color::new_rgb(red, green, blue).convert::<C>();
+
+        for (actual, expected) in TestColour::components(converted.model())
+            .into_iter()
+            .zip(TestColour::components(colour.model()))
+        {
+            assert_close(actual, expected, TestColour::TOLERANCE);
+        }
+    }
+
+    /// Checks that each `select_*` method returns exactly the components that
+    /// were selected.
+    pub fn assert_selection<C: ColorModel>(colour: &C) {
+        macro_rules! assert_selects {
+            ($method:ident, $(($($selected:literal),+)),+) => {
+                $(
+                    let selection = colour.$method::<$($selected),+>();
 
-                rgb_asserts::<true, true, false>(color);
+                    assert_eq!(
+                        assert_selects!(@is_some selection, $($selected),+),
+                        ($($selected),+),
+                        "{} returned the wrong components",
+                        stringify!($method)
+                    );
+                )+
+            };
+            (@is_some $selection:ident, $a:literal, $b:literal, $c:literal) => {
+                ($selection.0.is_some(), $selection.1.is_some(), $selection.2.is_some())
+            };
+            (@is_some $selection:ident, $a:literal, $b:literal, $c:literal, $d:literal) => {
+                (
+                    $selection.0.is_some(),
+                    $selection.1.is_some(),
+                    $selection.2.is_some(),
+                    $selection.3.is_some(),
+                )
+            };
+        }
+
+        macro_rules! assert_selects_three {
+            ($($method:ident),+) => {
+                $(
+                    assert_selects!(
+                        $method,
+                        (false, false, false),
+                        (true, false, false),
+                        (false, true, false),
+                        (false, false, true),
+                        (true, true, false),
+                        (true, false, true),
+                        (false, true, true),
+                        (true, true, true)
+                    );
+                )+
+            };
+        }
+
+        assert_selects_three!(
+            select_rgb,
+            select_rgb_float,
+            select_hsv,
+            select_hsl,
+            select_xyz
+        );
+
+        assert_selects!(
+            select_cmyk,
+            (false, false, false, false),
+            (true, false, false, false),
+            (false, true, false, false),
+            (false, false, true, false),
+            (false, false, false, true),
+            (true, true, true, true)
+        );
+        assert_selects!(
+            select_cmyk_float,
+            (false, false, false, false),
+            (true, false, false, false),
+            (false, true, false, false),
+            (false, false, true, false),
+            (false, false, false, true),
+            (true, true, true, true)
+        );
+    }
+
+    /// Checks that 8-bit RGB converted into the model and back changes by at
+    /// most `tolerance`, sampling every channel in steps of `step`.
+    pub fn assert_rgb_round_trip<C: FromColorModel>(step: usize, tolerance: u8) {
+        for r in (0..=u8::MAX).step_by(step) {
+            for g in (0..=u8::MAX).step_by(step) {
+                for b in (0..=u8::MAX).step_by(step) {
+                    let round_trip = Color::new_rgb(r, g, b).convert::<C>().convert::<Rgb>();
+
+                    assert_bytes(
+                        &format!("round trip of ({r}, {g}, {b})"),
+                        &[round_trip.red(), round_trip.green(), round_trip.blue()],
+                        &[r, g, b],
+                        tolerance,
+                    );
+                }
             }
+        }
+    }
 
+    /// Generates the reference value, component selection and round trip tests
+    /// for a model. Round trips must be exact unless a tolerance is given.
+    macro_rules! colour_model_tests {
+        ($model:ty) => {
+            colour_model_tests!($model, 0);
+        };
+        ($model:ty, $round_trip_tolerance:expr) => {
             #[test]
-            fn test_select_rgb_rb_only() {
-                let color = $rb_only_color;
-
-                rgb_asserts::<true, false, true>(color);
+            fn test_reference_colours() {
+                for reference in &$crate::models::color_model::test_utils::reference_colours() {
+                    $crate::models::color_model::test_utils::assert_reference::<$model>(reference);
+                }
             }
 
             #[test]
-            fn test_select_rgb_gb_only() {
-                let color = $gb_only_color;
+            fn test_component_selection() {
+                use $crate::models::color_model::test_utils::GenerateColour;
 
-                rgb_asserts::<false, true, true>(color);
+                for reference in &$crate::models::color_model::test_utils::reference_colours() {
+                    $crate::models::color_model::test_utils::assert_selection::<$model>(
+                        &reference.generate(),
+                    );
+                }
             }
 
             #[test]
-            fn test_select_rgb_all() {
-                let color = $all_color;
+            fn test_sampled_rgb_round_trip() {
+                $crate::models::color_model::test_utils::assert_rgb_round_trip::<$model>(
+                    15,
+                    $round_trip_tolerance,
+                );
+            }
 
-                rgb_asserts::<true, true, true>(color);
+            /// Checks all 16M colours, which is slow without optimizations.
+            #[test]
+            #[ignore = "run with `cargo test --release -- --ignored`"]
+            fn test_exhaustive_rgb_round_trip() {
+                $crate::models::color_model::test_utils::assert_rgb_round_trip::<$model>(
+                    1,
+                    $round_trip_tolerance,
+                );
             }
         };
     }
 
-    pub(crate) use {
-        // assert_rgb_color,
-        // rgb_tests,
-        colour_model_tests,
-    };
+    pub(crate) use colour_model_tests;
 }
@@ -0,0 +1,112 @@
+use crate::{
+    color::Color,
+    models::{
+        AnyColor,
+        Cmyk,
+        CmykFloat,
+        Hsl,
+        Hsv,
+        Lab,
+        Lch,
+        LinearRgb,
+        Oklab,
+        Oklch,
+        Rgb,
+        RgbFloat,
+        Xyz,
+    },
+};
+
+/// Implements `From` in both directions between the first model and each of
+/// the rest, then recurses on the rest. A blanket implementation would
+/// overlap with `impl<T> From<T> for T`.
+macro_rules! impl_from_models {
+    ($first:ident $(, $rest:ident)*) => {
+        $(
+            impl From<Color<$first>> for Color<$rest> {
+                #[inline]
+                fn from(color: Color<$first>) -> Self {
+                    color.convert()
+                }
+            }
+
+            impl From<Color<$rest>> for Color<$first> {
+                #[inline]
+                fn from(color: Color<$rest>) -> Self {
+                    color.convert()
+                }
+            }
+        )*
+
+        impl_from_models!($($rest),*);
+    };
+    () => {};
+}
+
+impl_from_models!(
+    Rgb, RgbFloat, LinearRgb, Cmyk, CmykFloat, Hsl, Hsv, Xyz, Lab, Lch, Oklab, Oklch
+);
+
+/// Implements `From<Color<AnyColor>>` for each model.
+macro_rules! impl_from_any_color {
+    ($($model:ident),*) => {
+        $(
+            impl From<Color<AnyColor>> for Color<$model> {
+                #[inline]
+                fn from(color: Color<AnyColor>) -> Self {
+                    color.convert()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_any_color!(
+    Rgb, RgbFloat, LinearRgb, Cmyk, CmykFloat, Hsl, Hsv, Xyz, Lab, Lch, Oklab, Oklch
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        color::Color,
+        models::{
+            Alpha,
+            AnyColor,
+            Hsl,
+            Oklch,
+            Rgb,
+            color_model::test_utils::assert_close,
+        },
+        p32,
+    };
+
+    fn to_hex<T>(colour: T) -> String
+    where
+        Color<Rgb>: From<T>,
+    {
+        Color::<Rgb>::from(colour).to_hex_string()
+    }
+
+    #[test]
+    fn test_convert() {
+        let colour = Color::new_rgb(26, 43, 60);
+
+        let hsl: Color<Hsl> = colour.convert();
+        let oklch = colour.convert::<Oklch>();
+
+        assert_eq!(hsl.to_string(), colour.to_hsl().to_string());
+        assert_eq!(oklch.to_string(), colour.to_oklch().to_string());
+        assert_eq!(to_hex(hsl), "#1a2b3c");
+        assert_eq!(to_hex(oklch), "#1a2b3c");
+        assert_eq!(to_hex(Color::<AnyColor>::from(colour.to_lab())), "#1a2b3c");
+    }
+
+    #[test]
+    fn test_convert_keeps_alpha() {
+        let colour = Color::new_rgb(26, 43, 60).with_alpha(p32!(0.5));
+        let hsl = colour.convert::<Alpha<Hsl>>();
+
+        assert_close(f64::from(hsl.alpha().value()), 0.5, 1e-6);
+        assert_eq!(hsl.to_rgb().to_hex_string(), "#1a2b3c");
+    }
+}
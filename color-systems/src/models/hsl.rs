@@ -1,62 +1,45 @@
-use crate::{
-    circle_degrees::CircleDegrees,
-    color::Color,
-    models::{
-        color_model::ColorModel,
-        shared::{
-            get_rgb_using_float,
-            rgb_float_to_cmyk_float,
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        contrast::AdjustLightness,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp_percentage,
+        },
+        models::color_model::{
+            ColorModel,
+            FromColorModel,
+            OpaqueColorModel,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
     },
-    percentage_f32::PercentageF32,
 };
 
+/// The hue, saturation and luminosity of an sRGB colour.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsl {
-    hue:        CircleDegrees,
+    /// Hue.
+    hue:        CircleDegreesF32,
+    /// Saturation.
     saturation: PercentageF32,
+    /// Lightness.
     luminosity: PercentageF32,
 }
 
 impl ColorModel for Hsl {
-    #[inline]
-    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
-        let (c, m, y, k) = self.select_cmyk_float::<C, M, Y, K>();
-
-        (
-            c.map(PercentageF32::to_percent_of_u8),
-            m.map(PercentageF32::to_percent_of_u8),
-            y.map(PercentageF32::to_percent_of_u8),
-            k.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
-    #[inline]
-    fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_cmyk_float::<C, M, Y, K>(
-            r.expect("Red was not returned"),
-            g.expect("Green was not returned"),
-            b.expect("Blue was not returned"),
-        )
-    }
-
-    #[inline]
-    fn select_rgb<const R: bool, const G: bool, const B: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>) {
-        get_rgb_using_float::<R, G, B, Self>(self)
-    }
-
     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
         &self,
     ) -> (
@@ -71,9 +54,9 @@ impl ColorModel for Hsl {
         let a = s_raw * f32::min(l_raw, 1. - l_raw);
 
         let f = |n| {
-            let k = (n + (h_raw as f32 / 30.)) % 12.;
+            let k = (n + (h_raw / 30.)) % 12.;
 
-            PercentageF32::new_or_panic(
+            PercentageF32::new_clamped(
                 // l_raw - a * f32::max(-1., f32::min(k - 3., f32::min(9. - k, 1.))),
                 f32::mul_add(
                     -a,
@@ -89,17 +72,15 @@ impl ColorModel for Hsl {
     fn select_hsv<const H: bool, const S: bool, const V: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
         let s_raw = self.saturation.value();
         let l_raw = self.luminosity.value();
 
-        let v = const { V || S }.then(|| l_raw + s_raw * f32::min(l_raw, 1. - l_raw));
-        let s = S.then(|| {
-            let v = v.expect("Value was not calculated");
-
+        let v = const { V || S }.then(|| s_raw.mul_add(f32::min(l_raw, 1. - l_raw), l_raw));
+        let s = v.filter(|_| S).map(|v| {
             if v == 0. {
                 0.
             } else {
@@ -109,8 +90,8 @@ impl ColorModel for Hsl {
 
         (
             H.then_some(self.hue),
-            s.map(PercentageF32::new_or_panic),
-            v.map(PercentageF32::new_or_panic),
+            s.map(PercentageF32::new_clamped),
+            v.filter(|_| V).map(PercentageF32::new_clamped),
         )
     }
 
@@ -118,7 +99,7 @@ impl ColorModel for Hsl {
     fn select_hsl<const H: bool, const S: bool, const L: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
@@ -130,18 +111,105 @@ impl ColorModel for Hsl {
     }
 }
 
+impl OpaqueColorModel for Hsl {}
+
+impl ToCss for Hsl {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "hsl",
+            &[
+                CssValue::Degrees(f64::from(self.hue.value())),
+                CssValue::Percentage(f64::from(self.saturation.value()) * 100.),
+                CssValue::Percentage(f64::from(self.luminosity.value()) * 100.),
+            ],
+            alpha,
+            true,
+        )
+    }
+}
+
+/// The hue of greys, where the saturation is `0` or the luminosity is `0` or
+/// `1`, is powerless and takes the hue of the other colour.
+impl Interpolate for Hsl {
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self {
+        let chromatic_hue = |colour: &Self| {
+            let luminosity = colour.luminosity.value();
+
+            (colour.saturation.value() > 0. && luminosity > 0. && luminosity < 1.)
+                .then_some(colour.hue)
+        };
+
+        Self {
+            hue:        hue.interpolate_degrees(chromatic_hue(self), chromatic_hue(other), t),
+            saturation: lerp_percentage(self.saturation, other.saturation, t),
+            luminosity: lerp_percentage(self.luminosity, other.luminosity, t),
+        }
+    }
+}
+
+impl AdjustLightness for Hsl {
+    #[inline]
+    fn relative_lightness(&self) -> f64 {
+        f64::from(self.luminosity.value())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn with_relative_lightness(&self, lightness: f64) -> Self {
+        Self {
+            luminosity: PercentageF32::new_clamped(lightness as f32),
+            ..*self
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn with_scaled_chroma(&self, scale: f64) -> Self {
+        Self {
+            saturation: PercentageF32::new_clamped(self.saturation.value() * scale as f32),
+            ..*self
+        }
+    }
+}
+
+impl FromColorModel for Hsl {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_hsl()
+    }
+}
+
 impl<C: ColorModel> Color<C> {
+    /// Converts the colour to HSL.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_hsl`].
     #[inline]
     pub fn to_hsl(&self) -> Color<Hsl> {
-        let (h, s, l) = self.color.select_hsl::<true, true, true>();
+        self.try_to_hsl().expect("HSL values were not returned")
+    }
 
-        Color::new_hsl(
-            h.expect("Hue was not returned"),
-            s.expect("Saturation was not returned"),
-            l.expect("Luminosity was not returned"),
-        )
+    /// Converts the colour to HSL.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_hsl(&self) -> Result<Color<Hsl>, ColorError> {
+        match self.color.select_hsl::<true, true, true>() {
+            (Some(h), Some(s), Some(l)) => Ok(Color::new_hsl(h, s, l)),
+            _ => Err(ColorError::ComponentUnavailable("HSL")),
+        }
     }
 
+    /// Converts the colour to HSL, consuming it.
     #[inline]
     pub fn into_hsl(self) -> Color<Hsl> {
         self.to_hsl()
@@ -149,9 +217,11 @@ impl<C: ColorModel> Color<C> {
 }
 
 impl Color<Hsl> {
+    /// Creates a colour from its hue, saturation and luminosity.
     #[inline]
+    #[must_use]
     pub const fn new_hsl(
-        hue: CircleDegrees,
+        hue: CircleDegreesF32,
         saturation: PercentageF32,
         luminosity: PercentageF32,
     ) -> Self {
@@ -163,4 +233,54 @@ impl Color<Hsl> {
             },
         }
     }
+
+    /// Creates a new colour from a hue in degrees and a saturation and
+    /// luminosity in the range `0..=1`, failing if any are out of range.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::DegreesOutOfRange`] if the hue is outside of
+    /// `0..360`, or [`ColorError::PercentageOutOfRange`] if another
+    /// component is outside of `0..=1`.
+    #[inline]
+    pub fn try_new_hsl(hue: f32, saturation: f32, luminosity: f32) -> Result<Self, ColorError> {
+        Ok(Self::new_hsl(
+            CircleDegreesF32::try_new_exact(hue)?,
+            PercentageF32::try_new(saturation)?,
+            PercentageF32::try_new(luminosity)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Hsl,
+        crate::models::color_model::test_utils::{
+            GenerateColour,
+            TestColour,
+            colour_model_tests,
+        },
+    };
+
+    impl GenerateColour<Hsl> for TestColour {
+        const TOLERANCE: f64 = 0.005;
+
+        fn generate(&self) -> Hsl {
+            Hsl {
+                hue:        self.hsl.0,
+                saturation: self.hsl.1,
+                luminosity: self.hsl.2,
+            }
+        }
+
+        fn components(colour: &Hsl) -> Vec<f64> {
+            vec![
+                f64::from(colour.hue.value()) / 360.,
+                f64::from(colour.saturation.value()),
+                f64::from(colour.luminosity.value()),
+            ]
+        }
+    }
+
+    colour_model_tests!(Hsl);
 }
@@ -1,62 +1,44 @@
-use crate::{
-    circle_degrees::CircleDegrees,
-    color::Color,
-    models::{
-        color_model::ColorModel,
-        shared::{
-            get_rgb_using_float,
-            rgb_float_to_cmyk_float,
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp_percentage,
+        },
+        models::color_model::{
+            ColorModel,
+            FromColorModel,
+            OpaqueColorModel,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
     },
-    percentage_f32::PercentageF32,
 };
 
+/// The hue, saturation and value of an sRGB colour.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsv {
-    hue:        CircleDegrees,
+    /// Hue.
+    hue:        CircleDegreesF32,
+    /// Saturation.
     saturation: PercentageF32,
+    /// Value.
     value:      PercentageF32,
 }
 
 impl ColorModel for Hsv {
-    #[inline]
-    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
-        let (c, m, y, k) = self.select_cmyk_float::<C, M, Y, K>();
-
-        (
-            c.map(PercentageF32::to_percent_of_u8),
-            m.map(PercentageF32::to_percent_of_u8),
-            y.map(PercentageF32::to_percent_of_u8),
-            k.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
-    #[inline]
-    fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_cmyk_float::<C, M, Y, K>(
-            r.expect("Red was not returned"),
-            g.expect("Green was not returned"),
-            b.expect("Blue was not returned"),
-        )
-    }
-
-    #[inline]
-    fn select_rgb<const R: bool, const G: bool, const B: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>) {
-        get_rgb_using_float::<R, G, B, Self>(self)
-    }
-
     fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
         &self,
     ) -> (
@@ -69,10 +51,10 @@ impl ColorModel for Hsv {
         let v_raw = self.value.value();
 
         let f = |n| {
-            let k = (n + (h_raw as f32 / 60.)) % 6.;
+            let k = (n + (h_raw / 60.)) % 6.;
 
-            PercentageF32::new_or_panic(
-                v_raw - v_raw * s_raw * f32::max(0., f32::min(k, f32::min(4. - k, 1.))),
+            PercentageF32::new_clamped(
+                (v_raw * s_raw).mul_add(-f32::max(0., f32::min(k, f32::min(4. - k, 1.))), v_raw),
             )
         };
 
@@ -83,7 +65,7 @@ impl ColorModel for Hsv {
     fn select_hsv<const H: bool, const S: bool, const V: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
@@ -94,10 +76,13 @@ impl ColorModel for Hsv {
         )
     }
 
+    // Exact comparisons are intended, as saturation is undefined only at the
+    // extremes.
+    #[allow(clippy::float_cmp)]
     fn select_hsl<const H: bool, const S: bool, const L: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
@@ -106,45 +91,125 @@ impl ColorModel for Hsv {
 
         let l = const { L || S }.then(|| v_raw * (1. - (s_raw / 2.)));
 
-        let s = S.then(|| {
+        let s = l.filter(|_| S).map(|l_val| {
             if v_raw == 0. || v_raw == 1. {
                 0.
             } else {
-                let l_val = l.expect("Luminosity was not calculated");
-
                 (v_raw - l_val) / f32::min(l_val, 1. - l_val)
             }
         });
 
         (
             H.then_some(self.hue),
-            s.map(PercentageF32::new_or_panic),
-            l.map(PercentageF32::new_or_panic),
+            s.map(PercentageF32::new_clamped),
+            l.filter(|_| L).map(PercentageF32::new_clamped),
         )
     }
 }
 
+impl OpaqueColorModel for Hsv {}
+
+/// HSV is written as the equivalent `hwb()` colour.
+impl ToCss for Hsv {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let saturation = f64::from(self.saturation.value());
+        let value = f64::from(self.value.value());
+
+        formatter.write_function(
+            out,
+            "hwb",
+            &[
+                CssValue::Degrees(f64::from(self.hue.value())),
+                CssValue::Percentage((1. - saturation) * value * 100.),
+                CssValue::Percentage((1. - value) * 100.),
+            ],
+            alpha,
+            false,
+        )
+    }
+}
+
+/// The hue of greys, where the saturation or value is `0`, is powerless and
+/// takes the hue of the other colour.
+impl Interpolate for Hsv {
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self {
+        let chromatic_hue = |colour: &Self| {
+            (colour.saturation.value() > 0. && colour.value.value() > 0.).then_some(colour.hue)
+        };
+
+        Self {
+            hue:        hue.interpolate_degrees(chromatic_hue(self), chromatic_hue(other), t),
+            saturation: lerp_percentage(self.saturation, other.saturation, t),
+            value:      lerp_percentage(self.value, other.value, t),
+        }
+    }
+}
+
+impl FromColorModel for Hsv {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_hsv()
+    }
+}
+
 impl<C: ColorModel> Color<C> {
-    pub fn as_hsv(&self) -> Color<Hsv> {
-        let (h, s, v) = self.color.select_hsv::<true, true, true>();
+    /// Converts the colour to HSV.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_hsv`].
+    pub fn to_hsv(&self) -> Color<Hsv> {
+        self.try_to_hsv().expect("HSV values were not returned")
+    }
 
-        Color {
-            color: Hsv {
-                hue:        h.expect("Hue was not returned"),
-                saturation: s.expect("Saturation was not returned"),
-                value:      v.expect("Value was not returned"),
-            },
+    /// Converts the colour to HSV.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    pub fn try_to_hsv(&self) -> Result<Color<Hsv>, ColorError> {
+        match self.color.select_hsv::<true, true, true>() {
+            (Some(h), Some(s), Some(v)) => Ok(Color::new_hsv(h, s, v)),
+            _ => Err(ColorError::ComponentUnavailable("HSV")),
         }
     }
 
+    /// Converts the colour to HSV, consuming it.
     pub fn into_hsv(self) -> Color<Hsv> {
-        self.as_hsv()
+        self.to_hsv()
+    }
+
+    /// Converts the colour to HSV.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_hsv`].
+    #[deprecated(note = "renamed to `to_hsv` to match the other conversions")]
+    pub fn as_hsv(&self) -> Color<Hsv> {
+        self.to_hsv()
+    }
+
+    /// Converts the colour to HSV.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[deprecated(note = "renamed to `try_to_hsv` to match the other conversions")]
+    pub fn try_as_hsv(&self) -> Result<Color<Hsv>, ColorError> {
+        self.try_to_hsv()
     }
 }
 
 impl Color<Hsv> {
+    /// Creates a colour from its hue, saturation and value.
+    #[must_use]
     pub const fn new_hsv(
-        hue: CircleDegrees,
+        hue: CircleDegreesF32,
         saturation: PercentageF32,
         value: PercentageF32,
     ) -> Self {
@@ -156,4 +221,64 @@ impl Color<Hsv> {
             },
         }
     }
+
+    /// Creates a new colour from a hue in degrees and a saturation and value
+    /// in the range `0..=1`, failing if any are out of range.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::DegreesOutOfRange`] if the hue is outside of
+    /// `0..360`, or [`ColorError::PercentageOutOfRange`] if another
+    /// component is outside of `0..=1`.
+    pub fn try_new_hsv(hue: f32, saturation: f32, value: f32) -> Result<Self, ColorError> {
+        Ok(Self::new_hsv(
+            CircleDegreesF32::try_new_exact(hue)?,
+            PercentageF32::try_new(saturation)?,
+            PercentageF32::try_new(value)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Hsv,
+        crate::{
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Hsv> for TestColour {
+        const TOLERANCE: f64 = 0.005;
+
+        fn generate(&self) -> Hsv {
+            Hsv {
+                hue:        self.hsv.0,
+                saturation: self.hsv.1,
+                value:      self.hsv.2,
+            }
+        }
+
+        fn components(colour: &Hsv) -> Vec<f64> {
+            vec![
+                f64::from(colour.hue.value()) / 360.,
+                f64::from(colour.saturation.value()),
+                f64::from(colour.value.value()),
+            ]
+        }
+    }
+
+    colour_model_tests!(Hsv);
+
+    #[test]
+    fn test_hue_keeps_fractional_degrees() {
+        let hue = Color::new_rgb(255, 1, 0).hue().value();
+
+        assert_close(f64::from(hue), 0.235_294, 1e-4);
+    }
 }
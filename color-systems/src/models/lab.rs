@@ -0,0 +1,376 @@
+use {
+    crate::{
+        advanced::{
+            D50_STANDARD_ILLUMINANT,
+            D65_STANDARD_ILLUMINANT,
+            MatrixRow,
+        },
+        color::Color,
+        contrast::AdjustLightness,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                ensure_finite,
+                lab_to_lch,
+                lab_to_xyz,
+                xyz_to_lab,
+                xyz_to_rgb_float,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// CIE 1976 L\*a\*b\*, relative to a reference white.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+    /// Lightness (`L*`).
+    lightness: f64,
+    /// Green–red (`a*`).
+    a: f64,
+    /// Blue–yellow (`b*`).
+    b: f64,
+    /// XYZ of the reference white.
+    reference_white: MatrixRow,
+}
+
+impl Lab {
+    /// Converts the colour to XYZ.
+    #[inline]
+    fn to_xyz(self) -> MatrixRow {
+        lab_to_xyz(&[self.lightness, self.a, self.b], &self.reference_white)
+    }
+}
+
+impl ColorModel for Lab {
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        let [x, y, z] = self.to_xyz();
+
+        xyz_to_rgb_float::<R, G, B>(x, y, z)
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let [x, y, z] = self.to_xyz();
+
+        (X.then_some(x), Y.then_some(y), Z.then_some(z))
+    }
+}
+
+impl OpaqueColorModel for Lab {}
+
+/// CSS `lab()` is relative to D50, so the colour is adapted if necessary.
+impl ToCss for Lab {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let lab = xyz_to_lab(&self.to_xyz(), &D50_STANDARD_ILLUMINANT);
+
+        formatter.write_function(out, "lab", &lab.map(CssValue::Number), alpha, false)
+    }
+}
+
+/// `other` is adapted to the reference white of this colour if they differ.
+impl Interpolate for Lab {
+    // Exact equality is intended, as identical white points need no adaptation.
+    #[allow(clippy::float_cmp)]
+    fn interpolate(&self, other: &Self, t: f32, _hue: HueInterpolation) -> Self {
+        let [lightness, a, b] = if self.reference_white == other.reference_white {
+            [other.lightness, other.a, other.b]
+        } else {
+            xyz_to_lab(&other.to_xyz(), &self.reference_white)
+        };
+
+        Self {
+            lightness: lerp(self.lightness, lightness, t),
+            a: lerp(self.a, a, t),
+            b: lerp(self.b, b, t),
+            reference_white: self.reference_white,
+        }
+    }
+}
+
+impl AdjustLightness for Lab {
+    #[inline]
+    fn relative_lightness(&self) -> f64 {
+        self.lightness / 100.
+    }
+
+    #[inline]
+    fn with_relative_lightness(&self, lightness: f64) -> Self {
+        Self {
+            lightness: lightness.clamp(0., 1.) * 100.,
+            ..*self
+        }
+    }
+
+    #[inline]
+    fn with_scaled_chroma(&self, scale: f64) -> Self {
+        Self {
+            a: self.a * scale,
+            b: self.b * scale,
+            ..*self
+        }
+    }
+}
+
+impl FromColorModel for Lab {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_lab()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to CIELAB relative to D65.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_lab`].
+    #[inline]
+    pub fn to_lab(&self) -> Color<Lab> {
+        self.try_to_lab().expect("Lab values were not returned")
+    }
+
+    /// Converts the colour to CIELAB relative to D65.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_lab(&self) -> Result<Color<Lab>, ColorError> {
+        self.try_to_lab_with_white(D65_STANDARD_ILLUMINANT)
+    }
+
+    /// Converts the colour to CIELAB relative to D65, consuming it.
+    #[inline]
+    pub fn into_lab(self) -> Color<Lab> {
+        self.to_lab()
+    }
+
+    /// Converts to CIELAB relative to the given reference white, expressed in
+    /// XYZ like [`D65_STANDARD_ILLUMINANT`]. Colours are adapted to the
+    /// reference white using the Bradford transform.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_lab_with_white`].
+    #[inline]
+    pub fn to_lab_with_white(&self, reference_white: MatrixRow) -> Color<Lab> {
+        self.try_to_lab_with_white(reference_white)
+            .expect("Lab values were not returned")
+    }
+
+    /// Converts to CIELAB relative to the given reference white, expressed in
+    /// XYZ like [`D65_STANDARD_ILLUMINANT`].
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_lab_with_white(
+        &self,
+        reference_white: MatrixRow,
+    ) -> Result<Color<Lab>, ColorError> {
+        let [lightness, a, b] = xyz_to_lab(&self.try_xyz_tristimulus()?, &reference_white);
+
+        Ok(Color::new_lab_with_white(lightness, a, b, reference_white))
+    }
+
+    /// CIELAB lightness (`L*`) relative to D65, in the range `0..=100`.
+    #[inline]
+    pub fn lightness(&self) -> f64 {
+        self.lab_components()[0]
+    }
+
+    /// CIELAB `a*` (green–red) component relative to D65.
+    #[inline]
+    pub fn a(&self) -> f64 {
+        self.lab_components()[1]
+    }
+
+    /// CIELAB `b*` (blue–yellow) component relative to D65.
+    #[inline]
+    pub fn b(&self) -> f64 {
+        self.lab_components()[2]
+    }
+
+    /// CIELCh(ab) chroma (`C*`) relative to D65.
+    #[inline]
+    pub fn chroma(&self) -> f64 {
+        lab_to_lch(&self.lab_components())[1]
+    }
+
+    /// CIELAB components relative to D65.
+    #[inline]
+    pub(crate) fn lab_components(&self) -> MatrixRow {
+        xyz_to_lab(&self.xyz_tristimulus(), &D65_STANDARD_ILLUMINANT)
+    }
+}
+
+impl Color<Lab> {
+    /// Creates a CIELAB colour relative to D65.
+    #[inline]
+    #[must_use]
+    pub const fn new_lab(lightness: f64, a: f64, b: f64) -> Self {
+        Self::new_lab_with_white(lightness, a, b, D65_STANDARD_ILLUMINANT)
+    }
+
+    /// Creates a CIELAB colour relative to `reference_white`.
+    #[inline]
+    #[must_use]
+    pub const fn new_lab_with_white(
+        lightness: f64,
+        a: f64,
+        b: f64,
+        reference_white: MatrixRow,
+    ) -> Self {
+        Self {
+            color: Lab {
+                lightness,
+                a,
+                b,
+                reference_white,
+            },
+        }
+    }
+
+    /// Creates a new CIELAB colour relative to D65, failing if any component is
+    /// infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_lab(lightness: f64, a: f64, b: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, a, b])?;
+
+        Ok(Self::new_lab(lightness, a, b))
+    }
+
+    /// Creates a new CIELAB colour relative to `reference_white`, failing if
+    /// any component or the reference white is infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component or the reference white
+    /// is infinite or NaN.
+    #[inline]
+    pub fn try_new_lab_with_white(
+        lightness: f64,
+        a: f64,
+        b: f64,
+        reference_white: MatrixRow,
+    ) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, a, b])?;
+        ensure_finite(&reference_white)?;
+
+        Ok(Self::new_lab_with_white(lightness, a, b, reference_white))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Lab,
+        crate::{
+            advanced::{
+                D50_STANDARD_ILLUMINANT,
+                D65_STANDARD_ILLUMINANT,
+            },
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Lab> for TestColour {
+        const TOLERANCE: f64 = 0.05;
+
+        fn generate(&self) -> Lab {
+            Lab {
+                lightness: self.lab.0,
+                a: self.lab.1,
+                b: self.lab.2,
+                reference_white: D65_STANDARD_ILLUMINANT,
+            }
+        }
+
+        fn components(colour: &Lab) -> Vec<f64> {
+            vec![colour.lightness, colour.a, colour.b]
+        }
+    }
+
+    colour_model_tests!(Lab);
+
+    #[test]
+    fn test_reference_values() {
+        let red = Color::new_rgb(255, 0, 0);
+
+        assert_close(red.lightness(), 53.24, 1e-2);
+        assert_close(red.a(), 80.09, 1e-2);
+        assert_close(red.b(), 67.20, 1e-2);
+
+        let white = Color::new_rgb(255, 255, 255);
+
+        assert_close(white.lightness(), 100., 1e-2);
+        assert_close(white.a(), 0., 1e-2);
+        assert_close(white.b(), 0., 1e-2);
+    }
+
+    #[test]
+    fn test_d50_reference_white() {
+        let white = Color::new_rgb(255, 255, 255).to_lab_with_white(D50_STANDARD_ILLUMINANT);
+
+        assert_close(white.color.lightness, 100., 1e-2);
+        assert_close(white.color.a, 0., 1e-2);
+        assert_close(white.color.b, 0., 1e-2);
+
+        let red = Color::new_rgb(255, 0, 0).to_lab_with_white(D50_STANDARD_ILLUMINANT);
+
+        assert_close(red.color.lightness, 54.29, 1e-2);
+        assert_close(red.color.a, 80.81, 1e-2);
+        assert_close(red.color.b, 69.89, 1e-2);
+
+        let round_trip = red.to_rgb();
+
+        assert_eq!(
+            (round_trip.red(), round_trip.green(), round_trip.blue()),
+            (255, 0, 0)
+        );
+    }
+}
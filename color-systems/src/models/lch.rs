@@ -0,0 +1,378 @@
+use {
+    crate::{
+        advanced::{
+            D50_STANDARD_ILLUMINANT,
+            D65_STANDARD_ILLUMINANT,
+            MatrixRow,
+        },
+        color::Color,
+        contrast::AdjustLightness,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                ensure_finite,
+                lab_to_lch,
+                lab_to_xyz,
+                lch_to_lab,
+                xyz_to_lab,
+                xyz_to_rgb_float,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// CIELCh(ab), the cylindrical form of CIELAB, relative to a reference white.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lch {
+    /// Lightness (`L*`).
+    lightness:       f64,
+    /// Chroma (`C*`).
+    chroma:          f64,
+    /// Hue angle in degrees.
+    hue:             f64,
+    /// XYZ of the reference white.
+    reference_white: MatrixRow,
+}
+
+impl Lch {
+    /// Converts the colour to XYZ.
+    #[inline]
+    fn to_xyz(self) -> MatrixRow {
+        lab_to_xyz(
+            &lch_to_lab(&[self.lightness, self.chroma, self.hue]),
+            &self.reference_white,
+        )
+    }
+}
+
+impl ColorModel for Lch {
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        let [x, y, z] = self.to_xyz();
+
+        xyz_to_rgb_float::<R, G, B>(x, y, z)
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let [x, y, z] = self.to_xyz();
+
+        (X.then_some(x), Y.then_some(y), Z.then_some(z))
+    }
+}
+
+impl OpaqueColorModel for Lch {}
+
+/// CSS `lch()` is relative to D50, so the colour is adapted if necessary.
+impl ToCss for Lch {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        let [lightness, chroma, hue] =
+            lab_to_lch(&xyz_to_lab(&self.to_xyz(), &D50_STANDARD_ILLUMINANT));
+
+        formatter.write_function(
+            out,
+            "lch",
+            &[
+                CssValue::Number(lightness),
+                CssValue::Number(chroma),
+                CssValue::Degrees(hue),
+            ],
+            alpha,
+            false,
+        )
+    }
+}
+
+/// `other` is adapted to the reference white of this colour if they differ.
+/// The hue of greys, with a chroma below `0.01`, is powerless and takes the
+/// hue of the other colour.
+impl Interpolate for Lch {
+    // Exact equality is intended, as identical white points need no adaptation.
+    #[allow(clippy::float_cmp)]
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self {
+        const ACHROMATIC: f64 = 0.01;
+
+        let [lightness, chroma, other_hue] = if self.reference_white == other.reference_white {
+            [other.lightness, other.chroma, other.hue]
+        } else {
+            lab_to_lch(&xyz_to_lab(&other.to_xyz(), &self.reference_white))
+        };
+
+        Self {
+            lightness:       lerp(self.lightness, lightness, t),
+            chroma:          lerp(self.chroma, chroma, t),
+            hue:             hue.interpolate(
+                (self.chroma >= ACHROMATIC).then_some(self.hue),
+                (chroma >= ACHROMATIC).then_some(other_hue),
+                t,
+            ),
+            reference_white: self.reference_white,
+        }
+    }
+}
+
+impl AdjustLightness for Lch {
+    #[inline]
+    fn relative_lightness(&self) -> f64 {
+        self.lightness / 100.
+    }
+
+    #[inline]
+    fn with_relative_lightness(&self, lightness: f64) -> Self {
+        Self {
+            lightness: lightness.clamp(0., 1.) * 100.,
+            ..*self
+        }
+    }
+
+    #[inline]
+    fn with_scaled_chroma(&self, scale: f64) -> Self {
+        Self {
+            chroma: self.chroma * scale,
+            ..*self
+        }
+    }
+}
+
+impl FromColorModel for Lch {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_lch()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to CIELCh(ab) relative to D65.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_lch`].
+    #[inline]
+    pub fn to_lch(&self) -> Color<Lch> {
+        self.try_to_lch().expect("Lch values were not returned")
+    }
+
+    /// Converts the colour to CIELCh(ab) relative to D65.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_lch(&self) -> Result<Color<Lch>, ColorError> {
+        self.try_to_lch_with_white(D65_STANDARD_ILLUMINANT)
+    }
+
+    /// Converts the colour to CIELCh(ab) relative to D65, consuming it.
+    #[inline]
+    pub fn into_lch(self) -> Color<Lch> {
+        self.to_lch()
+    }
+
+    /// Converts to CIELCh(ab) relative to the given reference white, expressed
+    /// in XYZ like [`D65_STANDARD_ILLUMINANT`]. Colours are adapted to the
+    /// reference white using the Bradford transform.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_lch_with_white`].
+    #[inline]
+    pub fn to_lch_with_white(&self, reference_white: MatrixRow) -> Color<Lch> {
+        self.try_to_lch_with_white(reference_white)
+            .expect("Lch values were not returned")
+    }
+
+    /// Converts to CIELCh(ab) relative to the given reference white, expressed
+    /// in XYZ like [`D65_STANDARD_ILLUMINANT`].
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_lch_with_white(
+        &self,
+        reference_white: MatrixRow,
+    ) -> Result<Color<Lch>, ColorError> {
+        let [lightness, chroma, hue] =
+            lab_to_lch(&xyz_to_lab(&self.try_xyz_tristimulus()?, &reference_white));
+
+        Ok(Color::new_lch_with_white(
+            lightness,
+            chroma,
+            hue,
+            reference_white,
+        ))
+    }
+
+    /// CIELCh(ab) hue angle (`h`) in degrees relative to D65, in the range
+    /// `0..360`.
+    #[inline]
+    pub fn hue_lch(&self) -> f64 {
+        self.to_lch().color.hue
+    }
+}
+
+impl Color<Lch> {
+    /// Creates a CIELCh(ab) colour relative to D65, with the hue in degrees.
+    #[inline]
+    #[must_use]
+    pub const fn new_lch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        Self::new_lch_with_white(lightness, chroma, hue, D65_STANDARD_ILLUMINANT)
+    }
+
+    /// Creates a CIELCh(ab) colour relative to `reference_white`, with the hue
+    /// in degrees.
+    #[inline]
+    #[must_use]
+    pub const fn new_lch_with_white(
+        lightness: f64,
+        chroma: f64,
+        hue: f64,
+        reference_white: MatrixRow,
+    ) -> Self {
+        Self {
+            color: Lch {
+                lightness,
+                chroma,
+                hue,
+                reference_white,
+            },
+        }
+    }
+
+    /// Creates a new CIELCh(ab) colour relative to D65, failing if any
+    /// component is infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_lch(lightness: f64, chroma: f64, hue: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, chroma, hue])?;
+
+        Ok(Self::new_lch(lightness, chroma, hue))
+    }
+
+    /// Creates a new CIELCh(ab) colour relative to `reference_white`, failing
+    /// if any component or the reference white is infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component or the reference white
+    /// is infinite or NaN.
+    #[inline]
+    pub fn try_new_lch_with_white(
+        lightness: f64,
+        chroma: f64,
+        hue: f64,
+        reference_white: MatrixRow,
+    ) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, chroma, hue])?;
+        ensure_finite(&reference_white)?;
+
+        Ok(Self::new_lch_with_white(
+            lightness,
+            chroma,
+            hue,
+            reference_white,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Lch,
+        crate::{
+            advanced::D65_STANDARD_ILLUMINANT,
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Lch> for TestColour {
+        const TOLERANCE: f64 = 0.05;
+
+        fn generate(&self) -> Lch {
+            Lch {
+                lightness:       self.lch.0,
+                chroma:          self.lch.1,
+                hue:             self.lch.2,
+                reference_white: D65_STANDARD_ILLUMINANT,
+            }
+        }
+
+        fn components(colour: &Lch) -> Vec<f64> {
+            vec![colour.lightness, colour.chroma, colour.hue]
+        }
+    }
+
+    colour_model_tests!(Lch);
+
+    #[test]
+    fn test_reference_values() {
+        let red = Color::new_rgb(255, 0, 0);
+
+        assert_close(red.chroma(), 104.55, 1e-2);
+        assert_close(red.hue_lch(), 40., 1e-2);
+
+        let blue = Color::new_rgb(0, 0, 255);
+
+        assert_close(blue.hue_lch(), 306.29, 1e-2);
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        for (r, g, b) in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (0, 0, 255),
+            (161, 131, 114),
+            (92, 17, 73),
+            (222, 220, 120),
+        ] {
+            let round_trip = Color::new_rgb(r, g, b).to_lch().to_rgb();
+
+            assert_eq!(
+                (round_trip.red(), round_trip.green(), round_trip.blue()),
+                (r, g, b)
+            );
+        }
+    }
+}
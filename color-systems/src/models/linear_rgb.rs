@@ -0,0 +1,349 @@
+use {
+    crate::{
+        advanced::{
+            RgbXyzConverter,
+            TransferFunction,
+        },
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::ensure_finite,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// The PQ linear value of the BT.2408 HDR reference white, 203 cd/m² out of
+/// the 10,000 cd/m² that PQ can encode.
+const PQ_REFERENCE_WHITE: f64 = 203. / 10_000.;
+
+/// The HLG scene light of the BT.2408 HDR reference white, which is encoded
+/// as a signal of `0.75`.
+const HLG_REFERENCE_WHITE: f64 = 0.264_962_56;
+
+/// The linear value of `transfer_function` that is scaled to a linear-light
+/// component of `1`.
+const fn reference_white(transfer_function: TransferFunction) -> f64 {
+    match transfer_function {
+        TransferFunction::Pq => PQ_REFERENCE_WHITE,
+        TransferFunction::Hlg => HLG_REFERENCE_WHITE,
+        _ => 1.,
+    }
+}
+
+/// Linear-light RGB with the sRGB primaries and white point, where the
+/// components are proportional to the light emitted.
+///
+/// Blending, averaging and luminance calculations are only physically correct
+/// in linear light; [`RgbFloat`](crate::models::RgbFloat) stores the non-linear
+/// sRGB encoding instead. Components outside of `0..=1` are kept, for colours
+/// outside of the sRGB gamut and for brighter than white HDR values.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRgb {
+    /// Red.
+    red:   f64,
+    /// Green.
+    green: f64,
+    /// Blue.
+    blue:  f64,
+}
+
+impl ColorModel for LinearRgb {
+    #[allow(clippy::cast_possible_truncation)]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        let encode =
+            |c: f64| PercentageF32::new_clamped(TransferFunction::Srgb.to_encoded(c) as f32);
+
+        (
+            R.then(|| encode(self.red)),
+            G.then(|| encode(self.green)),
+            B.then(|| encode(self.blue)),
+        )
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let [x, y, z] =
+            RgbXyzConverter::SRGB_CONVERTER.rgb_to_xyz(&[self.red, self.green, self.blue]);
+
+        (X.then_some(x), Y.then_some(y), Z.then_some(z))
+    }
+}
+
+impl OpaqueColorModel for LinearRgb {}
+
+impl ToCss for LinearRgb {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "color",
+            &[
+                CssValue::Keyword("srgb-linear"),
+                CssValue::Number(self.red),
+                CssValue::Number(self.green),
+                CssValue::Number(self.blue),
+            ],
+            alpha,
+            false,
+        )
+    }
+}
+
+impl Interpolate for LinearRgb {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f32, _hue: HueInterpolation) -> Self {
+        Self {
+            red:   lerp(self.red, other.red, t),
+            green: lerp(self.green, other.green, t),
+            blue:  lerp(self.blue, other.blue, t),
+        }
+    }
+}
+
+impl FromColorModel for LinearRgb {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_linear_rgb()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to linear-light sRGB.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_linear_rgb`].
+    #[inline]
+    pub fn to_linear_rgb(&self) -> Color<LinearRgb> {
+        self.try_to_linear_rgb()
+            .expect("Linear RGB values were not returned")
+    }
+
+    /// Converts the colour to linear-light sRGB, keeping components outside of
+    /// the sRGB gamut.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_linear_rgb(&self) -> Result<Color<LinearRgb>, ColorError> {
+        let [red, green, blue] =
+            RgbXyzConverter::SRGB_CONVERTER.xyz_to_rgb(&self.try_xyz_tristimulus()?);
+
+        Ok(Color::new_linear_rgb(red, green, blue))
+    }
+
+    /// Converts the colour to linear-light sRGB, consuming it.
+    #[inline]
+    pub fn into_linear_rgb(self) -> Color<LinearRgb> {
+        self.to_linear_rgb()
+    }
+}
+
+impl Color<LinearRgb> {
+    /// Creates a colour from linear-light components, where `1` is the
+    /// brightness of sRGB white.
+    #[inline]
+    #[must_use]
+    pub const fn new_linear_rgb(red: f64, green: f64, blue: f64) -> Self {
+        Self {
+            color: LinearRgb { red, green, blue },
+        }
+    }
+
+    /// Creates a new colour from linear-light components, failing if any are
+    /// infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_linear_rgb(red: f64, green: f64, blue: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[red, green, blue])?;
+
+        Ok(Self::new_linear_rgb(red, green, blue))
+    }
+
+    /// The linear-light red, green and blue components.
+    #[inline]
+    #[must_use]
+    pub const fn linear_components(&self) -> [f64; 3] {
+        [self.color.red, self.color.green, self.color.blue]
+    }
+
+    /// Decodes components that were encoded with `transfer_function`. Only the
+    /// encoding is undone; the primaries are taken to be those of sRGB.
+    ///
+    /// For the HDR curves, the BT.2408 reference white becomes `1`: 203 cd/m²
+    /// for [`TransferFunction::Pq`], and a signal of `0.75` for
+    /// [`TransferFunction::Hlg`]. Brighter highlights are kept above `1`.
+    #[inline]
+    #[must_use]
+    pub fn decode(encoded: [f64; 3], transfer_function: TransferFunction) -> Self {
+        let white = reference_white(transfer_function);
+        let [red, green, blue] = encoded.map(|c| transfer_function.to_linear(c) / white);
+
+        Self::new_linear_rgb(red, green, blue)
+    }
+
+    /// Encodes the components with `transfer_function`, scaling them as
+    /// [`Self::decode`] does. Use [`Color::to_rgb_float`] for ordinary sRGB.
+    ///
+    /// The encoded signal is returned as raw components rather than as
+    /// [`RgbFloat`](crate::models::RgbFloat), because `RgbFloat` is always
+    /// sRGB encoded. Components outside of the curve's range are not clamped.
+    #[inline]
+    #[must_use]
+    pub fn encode(&self, transfer_function: TransferFunction) -> [f64; 3] {
+        let white = reference_white(transfer_function);
+
+        self.linear_components()
+            .map(|c| transfer_function.to_encoded(c * white))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::LinearRgb,
+        crate::{
+            advanced::TransferFunction,
+            color::Color,
+            models::{
+                RgbFloat,
+                color_model::test_utils::{
+                    GenerateColour,
+                    TestColour,
+                    assert_close,
+                    colour_model_tests,
+                },
+            },
+        },
+    };
+
+    impl GenerateColour<LinearRgb> for TestColour {
+        const TOLERANCE: f64 = 0.001;
+
+        fn generate(&self) -> LinearRgb {
+            LinearRgb {
+                red:   self.linear_rgb.0,
+                green: self.linear_rgb.1,
+                blue:  self.linear_rgb.2,
+            }
+        }
+
+        fn components(colour: &LinearRgb) -> Vec<f64> {
+            vec![colour.red, colour.green, colour.blue]
+        }
+    }
+
+    colour_model_tests!(LinearRgb);
+
+    #[test]
+    fn test_encode_and_decode() {
+        let grey = [f64::from(128u8) / 255.; 3];
+        let linear = Color::<LinearRgb>::decode(grey, TransferFunction::Srgb);
+
+        for c in linear.linear_components() {
+            assert_close(c, 0.215_861, 1e-4);
+        }
+
+        for transfer_function in [
+            TransferFunction::Srgb,
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Rec709,
+            TransferFunction::Pq,
+            TransferFunction::Hlg,
+        ] {
+            let round_trip =
+                Color::<LinearRgb>::decode(grey, transfer_function).encode(transfer_function);
+
+            assert_close(round_trip[0], grey[0], 1e-9);
+        }
+
+        let gamma = Color::<LinearRgb>::decode(grey, TransferFunction::Gamma(2.2));
+
+        assert_close(gamma.linear_components()[0], 0.219_520, 1e-4);
+    }
+
+    #[test]
+    fn test_hdr_reference_white() {
+        let white = Color::new_linear_rgb(1., 1., 1.);
+
+        // BT.2408 gives 58% as the PQ signal for 203 cd/m².
+        let pq = white.encode(TransferFunction::Pq);
+
+        assert_close(pq[0], 0.58, 1e-3);
+
+        let hlg = white.encode(TransferFunction::Hlg);
+
+        assert_close(hlg[0], 0.75, 1e-6);
+
+        // Highlights brighter than reference white are kept.
+        let peak = Color::<LinearRgb>::decode([1.; 3], TransferFunction::Pq);
+
+        assert_close(peak.linear_components()[0], 10_000. / 203., 1e-6);
+    }
+
+    #[test]
+    fn test_out_of_gamut_is_kept() {
+        let linear = Color::new_linear_rgb(-0.1, 0.5, 1.2)
+            .to_xyz()
+            .to_linear_rgb();
+
+        for (actual, expected) in linear.linear_components().into_iter().zip([-0.1, 0.5, 1.2]) {
+            assert_close(actual, expected, 1e-6);
+        }
+
+        let rgb = linear.to_rgb();
+
+        assert_eq!((rgb.red(), rgb.blue()), (0, 255));
+    }
+
+    #[test]
+    fn test_blending_in_linear_light() {
+        let black = Color::new_rgb(0, 0, 0);
+        let white = Color::new_rgb(255, 255, 255);
+
+        assert_eq!(
+            black.mix::<RgbFloat>(&white, 0.5).to_rgb().to_hex_string(),
+            "#808080"
+        );
+        assert_eq!(
+            black.mix::<LinearRgb>(&white, 0.5).to_rgb().to_hex_string(),
+            "#bcbcbc"
+        );
+    }
+}
@@ -1,8 +1,53 @@
+//! The colour models that a [`Color`](crate::Color) can be stored in.
+
+/// Colours with an alpha channel.
+mod alpha;
+/// Colours whose model is chosen at runtime.
+mod any;
+/// 8-bit CMYK.
 mod cmyk;
+/// CMYK with percentage components.
 mod cmyk_float;
 pub mod color_model;
+/// `From` conversions between the models.
+mod convert;
+/// HSL.
 mod hsl;
+/// HSV.
 mod hsv;
+/// CIELAB.
+mod lab;
+/// CIELCh(ab).
+mod lch;
+/// Linear-light sRGB.
+mod linear_rgb;
+/// Oklab.
+mod oklab;
+/// Oklch.
+mod oklch;
+/// 8-bit sRGB.
 mod rgb;
+/// sRGB with percentage components.
 mod rgb_float;
+/// Conversions shared between models.
 mod shared;
+/// CIE XYZ.
+mod xyz;
+
+pub(crate) use rgb::parse_hex_digits;
+pub use {
+    alpha::Alpha,
+    any::AnyColor,
+    cmyk::Cmyk,
+    cmyk_float::CmykFloat,
+    hsl::Hsl,
+    hsv::Hsv,
+    lab::Lab,
+    lch::Lch,
+    linear_rgb::LinearRgb,
+    oklab::Oklab,
+    oklch::Oklch,
+    rgb::Rgb,
+    rgb_float::RgbFloat,
+    xyz::Xyz,
+};
@@ -0,0 +1,281 @@
+use {
+    crate::{
+        advanced::MatrixRow,
+        color::Color,
+        contrast::AdjustLightness,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                ensure_finite,
+                oklab_to_xyz,
+                xyz_to_oklab,
+                xyz_to_rgb_float,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// Björn Ottosson's Oklab perceptual colour space.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab {
+    /// Perceptual lightness.
+    lightness: f64,
+    /// Green–red.
+    a:         f64,
+    /// Blue–yellow.
+    b:         f64,
+}
+
+impl Oklab {
+    /// Converts the colour to XYZ.
+    #[inline]
+    fn to_xyz(self) -> MatrixRow {
+        oklab_to_xyz(&[self.lightness, self.a, self.b])
+    }
+}
+
+impl ColorModel for Oklab {
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        let [x, y, z] = self.to_xyz();
+
+        xyz_to_rgb_float::<R, G, B>(x, y, z)
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let [x, y, z] = self.to_xyz();
+
+        (X.then_some(x), Y.then_some(y), Z.then_some(z))
+    }
+}
+
+impl OpaqueColorModel for Oklab {}
+
+impl ToCss for Oklab {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "oklab",
+            &[self.lightness, self.a, self.b].map(CssValue::Number),
+            alpha,
+            false,
+        )
+    }
+}
+
+impl Interpolate for Oklab {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f32, _hue: HueInterpolation) -> Self {
+        Self {
+            lightness: lerp(self.lightness, other.lightness, t),
+            a:         lerp(self.a, other.a, t),
+            b:         lerp(self.b, other.b, t),
+        }
+    }
+}
+
+impl AdjustLightness for Oklab {
+    #[inline]
+    fn relative_lightness(&self) -> f64 {
+        self.lightness
+    }
+
+    #[inline]
+    fn with_relative_lightness(&self, lightness: f64) -> Self {
+        Self {
+            lightness: lightness.clamp(0., 1.),
+            ..*self
+        }
+    }
+
+    #[inline]
+    fn with_scaled_chroma(&self, scale: f64) -> Self {
+        Self {
+            a: self.a * scale,
+            b: self.b * scale,
+            ..*self
+        }
+    }
+}
+
+impl FromColorModel for Oklab {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_oklab()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to Oklab.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_oklab`].
+    #[inline]
+    pub fn to_oklab(&self) -> Color<Oklab> {
+        self.try_to_oklab().expect("Oklab values were not returned")
+    }
+
+    /// Converts the colour to Oklab.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_oklab(&self) -> Result<Color<Oklab>, ColorError> {
+        let [lightness, a, b] = xyz_to_oklab(&self.try_xyz_tristimulus()?);
+
+        Ok(Color::new_oklab(lightness, a, b))
+    }
+
+    /// Converts the colour to Oklab, consuming it.
+    #[inline]
+    pub fn into_oklab(self) -> Color<Oklab> {
+        self.to_oklab()
+    }
+}
+
+impl Color<Oklab> {
+    /// Creates a new Oklab colour, where `lightness` is in the range `0..=1`.
+    #[inline]
+    #[must_use]
+    pub const fn new_oklab(lightness: f64, a: f64, b: f64) -> Self {
+        Self {
+            color: Oklab { lightness, a, b },
+        }
+    }
+
+    /// Creates a new Oklab colour, failing if any component is infinite or
+    /// NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_oklab(lightness: f64, a: f64, b: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, a, b])?;
+
+        Ok(Self::new_oklab(lightness, a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Oklab,
+        crate::{
+            circle_degrees_f32,
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+            p32,
+        },
+    };
+
+    impl GenerateColour<Oklab> for TestColour {
+        const TOLERANCE: f64 = 0.001;
+
+        fn generate(&self) -> Oklab {
+            Oklab {
+                lightness: self.oklab.0,
+                a:         self.oklab.1,
+                b:         self.oklab.2,
+            }
+        }
+
+        fn components(colour: &Oklab) -> Vec<f64> {
+            vec![colour.lightness, colour.a, colour.b]
+        }
+    }
+
+    colour_model_tests!(Oklab);
+
+    #[test]
+    fn test_reference_values() {
+        let red = Color::new_rgb(255, 0, 0).to_oklab();
+
+        assert_close(red.color.lightness, 0.627_955, 1e-3);
+        assert_close(red.color.a, 0.224_863, 1e-3);
+        assert_close(red.color.b, 0.125_846, 1e-3);
+
+        let white = Color::new_rgb(255, 255, 255).to_oklab();
+
+        assert_close(white.color.lightness, 1., 1e-3);
+        assert_close(white.color.a, 0., 1e-3);
+        assert_close(white.color.b, 0., 1e-3);
+    }
+
+    #[test]
+    fn test_from_every_model() {
+        let expected = Color::new_rgb(0, 255, 0).to_oklab().color;
+
+        for oklab in [
+            Color::new_rgb_float(p32!(0.), p32!(1.), p32!(0.)).to_oklab(),
+            Color::new_hsl(circle_degrees_f32!(120.), p32!(1.), p32!(0.5)).to_oklab(),
+            Color::new_hsv(circle_degrees_f32!(120.), p32!(1.), p32!(1.)).to_oklab(),
+            Color::new_cmyk(255, 0, 255, 0).to_oklab(),
+            Color::new_cmyk_float(p32!(1.), p32!(0.), p32!(1.), p32!(0.)).to_oklab(),
+        ] {
+            assert_close(oklab.color.lightness, expected.lightness, 1e-3);
+            assert_close(oklab.color.a, expected.a, 1e-3);
+            assert_close(oklab.color.b, expected.b, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        for (r, g, b) in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (1, 7, 20),
+            (161, 131, 114),
+            (92, 17, 73),
+            (222, 220, 120),
+        ] {
+            let round_trip = Color::new_rgb(r, g, b).to_oklab().to_rgb();
+
+            assert_eq!(
+                (round_trip.red(), round_trip.green(), round_trip.blue()),
+                (r, g, b)
+            );
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use {
+    crate::{
+        advanced::MatrixRow,
+        color::Color,
+        contrast::AdjustLightness,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                ensure_finite,
+                lab_to_lch,
+                lch_to_lab,
+                oklab_to_xyz,
+                xyz_to_oklab,
+                xyz_to_rgb_float,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// Oklch, the cylindrical form of Oklab.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklch {
+    /// Perceptual lightness.
+    lightness: f64,
+    /// Chroma.
+    chroma:    f64,
+    /// Hue angle in degrees.
+    hue:       f64,
+}
+
+impl Oklch {
+    /// Converts the colour to XYZ.
+    #[inline]
+    fn to_xyz(self) -> MatrixRow {
+        oklab_to_xyz(&lch_to_lab(&[self.lightness, self.chroma, self.hue]))
+    }
+}
+
+impl ColorModel for Oklch {
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        let [x, y, z] = self.to_xyz();
+
+        xyz_to_rgb_float::<R, G, B>(x, y, z)
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let [x, y, z] = self.to_xyz();
+
+        (X.then_some(x), Y.then_some(y), Z.then_some(z))
+    }
+}
+
+impl OpaqueColorModel for Oklch {}
+
+impl ToCss for Oklch {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "oklch",
+            &[
+                CssValue::Number(self.lightness),
+                CssValue::Number(self.chroma),
+                CssValue::Degrees(self.hue),
+            ],
+            alpha,
+            false,
+        )
+    }
+}
+
+/// The hue of greys, with a chroma below `0.0001`, is powerless and takes the
+/// hue of the other colour.
+impl Interpolate for Oklch {
+    fn interpolate(&self, other: &Self, t: f32, hue: HueInterpolation) -> Self {
+        const ACHROMATIC: f64 = 0.0001;
+
+        let chromatic_hue = |colour: &Self| (colour.chroma >= ACHROMATIC).then_some(colour.hue);
+
+        Self {
+            lightness: lerp(self.lightness, other.lightness, t),
+            chroma:    lerp(self.chroma, other.chroma, t),
+            hue:       hue.interpolate(chromatic_hue(self), chromatic_hue(other), t),
+        }
+    }
+}
+
+impl AdjustLightness for Oklch {
+    #[inline]
+    fn relative_lightness(&self) -> f64 {
+        self.lightness
+    }
+
+    #[inline]
+    fn with_relative_lightness(&self, lightness: f64) -> Self {
+        Self {
+            lightness: lightness.clamp(0., 1.),
+            ..*self
+        }
+    }
+
+    #[inline]
+    fn with_scaled_chroma(&self, scale: f64) -> Self {
+        Self {
+            chroma: self.chroma * scale,
+            ..*self
+        }
+    }
+}
+
+impl FromColorModel for Oklch {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_oklch()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to Oklch.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_oklch`].
+    #[inline]
+    pub fn to_oklch(&self) -> Color<Oklch> {
+        self.try_to_oklch().expect("Oklch values were not returned")
+    }
+
+    /// Converts the colour to Oklch.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_oklch(&self) -> Result<Color<Oklch>, ColorError> {
+        let [lightness, chroma, hue] = lab_to_lch(&xyz_to_oklab(&self.try_xyz_tristimulus()?));
+
+        Ok(Color::new_oklch(lightness, chroma, hue))
+    }
+
+    /// Converts the colour to Oklch, consuming it.
+    #[inline]
+    pub fn into_oklch(self) -> Color<Oklch> {
+        self.to_oklch()
+    }
+
+    /// Oklch hue angle in degrees, in the range `0..360`.
+    #[inline]
+    pub fn hue_oklch(&self) -> f64 {
+        self.to_oklch().color.hue
+    }
+}
+
+impl Color<Oklch> {
+    /// Creates a new Oklch colour, where `lightness` is in the range `0..=1`
+    /// and `hue` is in degrees.
+    #[inline]
+    #[must_use]
+    pub const fn new_oklch(lightness: f64, chroma: f64, hue: f64) -> Self {
+        Self {
+            color: Oklch {
+                lightness,
+                chroma,
+                hue,
+            },
+        }
+    }
+
+    /// Creates a new Oklch colour, failing if any component is infinite or
+    /// NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_oklch(lightness: f64, chroma: f64, hue: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[lightness, chroma, hue])?;
+
+        Ok(Self::new_oklch(lightness, chroma, hue))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Oklch,
+        crate::{
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Oklch> for TestColour {
+        const TOLERANCE: f64 = 0.001;
+
+        fn generate(&self) -> Oklch {
+            Oklch {
+                lightness: self.oklch.0,
+                chroma:    self.oklch.1,
+                hue:       self.oklch.2,
+            }
+        }
+
+        fn components(colour: &Oklch) -> Vec<f64> {
+            vec![colour.lightness, colour.chroma, colour.hue / 360.]
+        }
+    }
+
+    colour_model_tests!(Oklch);
+
+    #[test]
+    fn test_interpolation_with_grey_keeps_hue() {
+        let red = Color::new_rgb(255, 0, 0);
+
+        for grey in [Color::new_rgb(255, 255, 255), Color::new_rgb(128, 128, 128)] {
+            let mixed = red.mix::<Oklch>(&grey, 0.5).into_model();
+
+            assert_close(mixed.hue, red.to_oklch().color.hue, 0.01);
+        }
+    }
+
+    #[test]
+    fn test_reference_values() {
+        let red = Color::new_rgb(255, 0, 0).to_oklch();
+
+        assert_close(red.color.lightness, 0.627_955, 1e-3);
+        assert_close(red.color.chroma, 0.257_683, 1e-3);
+        assert_close(red.color.hue, 29.23, 1e-2);
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        for (r, g, b) in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (0, 0, 255),
+            (161, 131, 114),
+            (92, 17, 73),
+            (222, 220, 120),
+        ] {
+            let round_trip = Color::new_rgb(r, g, b).to_oklch().to_rgb();
+
+            assert_eq!(
+                (round_trip.red(), round_trip.green(), round_trip.blue()),
+                (r, g, b)
+            );
+        }
+    }
+}
@@ -1,53 +1,38 @@
-use crate::{
-    circle_degrees::CircleDegrees,
-    color::Color,
-    models::{
-        color_model::ColorModel,
-        shared::{
-            rgb_float_to_cmyk_float,
-            rgb_float_to_hsvl,
+use {
+    crate::{
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        models::color_model::{
+            ColorModel,
+            FromColorModel,
+            OpaqueColorModel,
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
     },
-    percentage_f32::PercentageF32,
 };
 
+/// 8-bit sRGB, where each component is from `0` to `255`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgb {
+    /// Red.
     red:   u8,
+    /// Green.
     green: u8,
+    /// Blue.
     blue:  u8,
 }
 
 impl ColorModel for Rgb {
-    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
-        let (c, m, y, k) = self.select_cmyk_float::<C, M, Y, K>();
-
-        (
-            c.map(PercentageF32::to_percent_of_u8),
-            m.map(PercentageF32::to_percent_of_u8),
-            y.map(PercentageF32::to_percent_of_u8),
-            k.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
-    fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_cmyk_float::<C, M, Y, K>(
-            r.expect("Red was not returned"),
-            g.expect("Green was not returned"),
-            b.expect("Blue was not returned"),
-        )
-    }
-
     #[inline]
     fn select_rgb<const R: bool, const G: bool, const B: bool>(
         &self,
@@ -73,56 +58,63 @@ impl ColorModel for Rgb {
             B.then(|| PercentageF32::from_percent_of_u8(self.blue)),
         )
     }
+}
 
-    #[inline]
-    fn select_hsv<const H: bool, const S: bool, const V: bool>(
+impl OpaqueColorModel for Rgb {}
+
+impl ToCss for Rgb {
+    fn write_css(
         &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        if formatter.uses_hex() {
+            return formatter.write_hex(out, [self.red, self.green, self.blue], alpha);
+        }
 
-        rgb_float_to_hsvl::<H, S, V, false>(
-            r.expect("Red was not calculated"),
-            g.expect("Green was not calculated"),
-            b.expect("Blue was not calculated"),
+        formatter.write_function(
+            out,
+            "rgb",
+            &[self.red, self.green, self.blue].map(|c| CssValue::Number(f64::from(c))),
+            alpha,
+            true,
         )
     }
+}
 
+impl FromColorModel for Rgb {
     #[inline]
-    fn select_hsl<const H: bool, const S: bool, const L: bool>(
-        &self,
-    ) -> (
-        Option<CircleDegrees>,
-        Option<PercentageF32>,
-        Option<PercentageF32>,
-    ) {
-        let (r, g, b) = self.select_rgb_float::<true, true, true>();
-
-        rgb_float_to_hsvl::<H, S, L, true>(
-            r.expect("Red was not calculated"),
-            g.expect("Green was not calculated"),
-            b.expect("Blue was not calculated"),
-        )
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_rgb()
     }
 }
 
 impl<C: ColorModel> Color<C> {
+    /// Converts the colour to 8-bit sRGB.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_rgb`].
     #[inline]
     pub fn to_rgb(&self) -> Color<Rgb> {
-        let (r, g, b) = self.color.select_rgb::<true, true, true>();
+        self.try_to_rgb().expect("RGB values were not returned")
+    }
 
-        Color {
-            color: Rgb {
-                red:   r.expect("Red value was not returned"),
-                green: g.expect("Green value was not returned"),
-                blue:  b.expect("Blue values was not returned"),
-            },
+    /// Converts the colour to 8-bit sRGB.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_rgb(&self) -> Result<Color<Rgb>, ColorError> {
+        match self.color.select_rgb::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => Ok(Color::new_rgb(r, g, b)),
+            _ => Err(ColorError::ComponentUnavailable("RGB")),
         }
     }
 
+    /// Converts the colour to 8-bit sRGB, consuming it.
     #[inline]
     pub fn into_rgb(self) -> Color<Rgb> {
         self.to_rgb()
@@ -130,67 +122,175 @@ impl<C: ColorModel> Color<C> {
 }
 
 impl Color<Rgb> {
+    /// Creates a colour from a `0xRRGGBB` value. The top 8 bits are ignored;
+    /// use [`Self::try_from_hex`] to reject them.
     #[inline]
+    #[must_use]
     pub const fn from_hex(from: u32) -> Self {
-        Self {
-            color: Rgb {
-                red:   (from >> const { 2 * u8::BITS }) as u8,
-                green: (from >> u8::BITS) as u8,
-                blue:  from as u8,
-            },
+        let [_, red, green, blue] = from.to_be_bytes();
+
+        Self::new_rgb(red, green, blue)
+    }
+
+    /// Creates a new colour from a `0xRRGGBB` value, failing if any of the
+    /// top 8 bits are set.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::HexOutOfRange`] if any of the top 8 bits are set.
+    #[inline]
+    pub const fn try_from_hex(from: u32) -> Result<Self, ColorError> {
+        if from > 0x00FF_FFFF {
+            return Err(ColorError::HexOutOfRange(from));
         }
+
+        Ok(Self::from_hex(from))
     }
 
+    /// Parses a hex colour such as `#1a2b3c`, `1a2b3c` or `#abc`. The 4 and 8
+    /// digit forms are accepted as long as the alpha is fully opaque; use
+    /// [`Color::from_hex_rgba_str`] to keep a translucent alpha.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::InvalidSyntax`] if the string is not 3, 4, 6 or 8
+    /// hex digits, or if its alpha is not fully opaque.
     #[inline]
+    pub fn from_hex_str(from: &str) -> Result<Self, ColorError> {
+        match parse_hex_digits(from)? {
+            (rgb, u8::MAX) => Ok(Self::from_hex(rgb)),
+            _ => Err(ColorError::InvalidSyntax("hex colour is not opaque")),
+        }
+    }
+
+    /// Creates a colour from 8-bit sRGB components.
+    #[inline]
+    #[must_use]
     pub const fn new_rgb(red: u8, green: u8, blue: u8) -> Self {
         Self {
             color: Rgb { red, green, blue },
         }
     }
+
+    /// The colour as a `0xRRGGBB` value.
+    #[inline]
+    #[must_use]
+    pub const fn to_hex_u32(&self) -> u32 {
+        u32::from_be_bytes([0, self.color.red, self.color.green, self.color.blue])
+    }
+
+    /// The colour as a lowercase `#rrggbb` string.
+    #[inline]
+    #[must_use]
+    pub fn to_hex_string(&self) -> String {
+        format!("#{:06x}", self.to_hex_u32())
+    }
+}
+
+/// Parses 3, 4, 6 or 8 hex digits, with an optional leading `#`, into a
+/// `0xRRGGBB` value and an alpha byte.
+pub fn parse_hex_digits(from: &str) -> Result<(u32, u8), ColorError> {
+    let digits = from.strip_prefix('#').unwrap_or(from);
+
+    if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return Err(ColorError::InvalidSyntax("invalid hex digit"));
+    }
+
+    let expanded: String = match digits.len() {
+        3 | 4 => digits.chars().flat_map(|digit| [digit, digit]).collect(),
+        6 | 8 => digits.to_owned(),
+        _ => {
+            return Err(ColorError::InvalidSyntax(
+                "expected 3, 4, 6 or 8 hex digits",
+            ));
+        },
+    };
+
+    let value =
+        u32::from_str_radix(&expanded, 16).map_err(|_| ColorError::InvalidSyntax("invalid hex"))?;
+
+    Ok(if expanded.len() == 8 {
+        (value >> u8::BITS, value.to_be_bytes()[3])
+    } else {
+        (value, u8::MAX)
+    })
 }
 
 #[cfg(test)]
 mod test {
-    // rgb_tests!(
-    //     Rgb {
-    //         red:   161,
-    //         green: 131,
-    //         blue:  114,
-    //     },
-    //     Rgb {
-    //         red:   181,
-    //         green: 134,
-    //         blue:  177,
-    //     },
-    //     Rgb {
-    //         red:   138,
-    //         green: 107,
-    //         blue:  17,
-    //     },
-    //     Rgb {
-    //         red:   1,
-    //         green: 7,
-    //         blue:  20,
-    //     },
-    //     Rgb {
-    //         red:   222,
-    //         green: 220,
-    //         blue:  120,
-    //     },
-    //     Rgb {
-    //         red:   122,
-    //         green: 38,
-    //         blue:  122,
-    //     },
-    //     Rgb {
-    //         red:   177,
-    //         green: 128,
-    //         blue:  92,
-    //     },
-    //     Rgb {
-    //         red:   92,
-    //         green: 17,
-    //         blue:  73,
-    //     }
-    // );
+    use {
+        super::Rgb,
+        crate::{
+            color::Color,
+            error::ColorError,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Rgb> for TestColour {
+        const TOLERANCE: f64 = 0.;
+
+        fn generate(&self) -> Rgb {
+            Rgb {
+                red:   self.rgb.0,
+                green: self.rgb.1,
+                blue:  self.rgb.2,
+            }
+        }
+
+        fn components(colour: &Rgb) -> Vec<f64> {
+            [colour.red, colour.green, colour.blue]
+                .map(f64::from)
+                .to_vec()
+        }
+    }
+
+    colour_model_tests!(Rgb);
+
+    #[test]
+    fn test_hex_round_trip() {
+        let colour = Color::new_rgb(0x1a, 0x2b, 0x3c);
+
+        assert_eq!(colour.to_hex_u32(), 0x1a_2b3c);
+        assert_eq!(colour.to_hex_string(), "#1a2b3c");
+        assert_eq!(Color::from_hex(0x00_0a0b).to_hex_string(), "#000a0b");
+
+        for hex in ["#1a2b3c", "1A2B3C", "#1a2b3cff"] {
+            assert_eq!(
+                Color::from_hex_str(hex).map(|c| c.to_hex_u32()),
+                Ok(0x1a_2b3c)
+            );
+        }
+
+        for hex in ["#abc", "abcf"] {
+            assert_eq!(
+                Color::from_hex_str(hex).map(|c| c.to_hex_u32()),
+                Ok(0xaa_bbcc)
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_hex() {
+        assert_eq!(
+            Color::try_from_hex(0x0100_0000).err(),
+            Some(ColorError::HexOutOfRange(0x0100_0000))
+        );
+        assert!(Color::try_from_hex(0x00FF_FFFF).is_ok());
+
+        for hex in [
+            "",
+            "#",
+            "#12",
+            "#12345",
+            "#1234567",
+            "##123",
+            "#12g",
+            "#1a2b3c80",
+        ] {
+            assert!(Color::from_hex_str(hex).is_err(), "{hex} was accepted");
+        }
+    }
 }
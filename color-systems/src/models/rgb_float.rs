@@ -1,37 +1,54 @@
-use crate::{
-    circle_degrees::CircleDegrees,
-    color::Color,
-    models::{
-        color_model::ColorModel,
-        shared::{
-            rgb_float_to_cmyk_float,
-            rgb_float_to_hsvl,
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
         },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp_percentage,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                rgb_float_to_cmyk_float,
+                rgb_float_to_hsvl,
+                rgb_float_to_xyz,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
     },
-    percentage_f32::PercentageF32,
 };
 
+/// sRGB, with each component stored as a percentage.
+///
+/// The components are non-linear sRGB encoded values, so averaging them is not
+/// the same as averaging light; see [`LinearRgb`](crate::models::LinearRgb).
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RgbFloat {
+    /// Red.
     red:   PercentageF32,
+    /// Green.
     green: PercentageF32,
+    /// Blue.
     blue:  PercentageF32,
 }
 
 impl ColorModel for RgbFloat {
-    #[inline]
-    fn select_cmyk<const C: bool, const M: bool, const Y: bool, const K: bool>(
-        &self,
-    ) -> (Option<u8>, Option<u8>, Option<u8>, Option<u8>) {
-        let (c, m, y, k) = self.select_cmyk_float::<C, M, Y, K>();
-
-        (
-            c.map(PercentageF32::to_percent_of_u8),
-            m.map(PercentageF32::to_percent_of_u8),
-            y.map(PercentageF32::to_percent_of_u8),
-            k.map(PercentageF32::to_percent_of_u8),
-        )
-    }
-
     #[inline]
     fn select_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
         &self,
@@ -71,7 +88,7 @@ impl ColorModel for RgbFloat {
     fn select_hsv<const H: bool, const S: bool, const V: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
@@ -81,33 +98,90 @@ impl ColorModel for RgbFloat {
     fn select_hsl<const H: bool, const S: bool, const L: bool>(
         &self,
     ) -> (
-        Option<CircleDegrees>,
+        Option<CircleDegreesF32>,
         Option<PercentageF32>,
         Option<PercentageF32>,
     ) {
         rgb_float_to_hsvl::<H, S, L, true>(self.red, self.green, self.blue)
     }
+
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        rgb_float_to_xyz::<X, Y, Z>(self.red, self.green, self.blue)
+    }
+}
+
+impl OpaqueColorModel for RgbFloat {}
+
+impl ToCss for RgbFloat {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "rgb",
+            &[self.red, self.green, self.blue]
+                .map(|c| CssValue::Number(f64::from(c.value()) * f64::from(u8::MAX))),
+            alpha,
+            true,
+        )
+    }
+}
+
+impl Interpolate for RgbFloat {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f32, _hue: HueInterpolation) -> Self {
+        Self {
+            red:   lerp_percentage(self.red, other.red, t),
+            green: lerp_percentage(self.green, other.green, t),
+            blue:  lerp_percentage(self.blue, other.blue, t),
+        }
+    }
+}
+
+impl FromColorModel for RgbFloat {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_rgb_float()
+    }
 }
 
 impl<C: ColorModel> Color<C> {
+    /// Converts the colour to sRGB percentages.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_rgb_float`].
     pub fn to_rgb_float(&self) -> Color<RgbFloat> {
-        let (r, g, b) = self.color.select_rgb_float::<true, true, true>();
+        self.try_to_rgb_float()
+            .expect("RGB values were not returned")
+    }
 
-        Color {
-            color: RgbFloat {
-                red:   r.expect("Red value not returned"),
-                green: g.expect("Green value not returned"),
-                blue:  b.expect("Blue value not returned"),
-            },
+    /// Converts the colour to sRGB percentages.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    pub fn try_to_rgb_float(&self) -> Result<Color<RgbFloat>, ColorError> {
+        match self.color.select_rgb_float::<true, true, true>() {
+            (Some(r), Some(g), Some(b)) => Ok(Color::new_rgb_float(r, g, b)),
+            _ => Err(ColorError::ComponentUnavailable("RGB")),
         }
     }
 
+    /// Converts the colour to sRGB percentages, consuming it.
     pub fn into_rgb_float(self) -> Color<RgbFloat> {
         self.to_rgb_float()
     }
 }
 
 impl Color<RgbFloat> {
+    /// Creates a colour from sRGB percentages.
+    #[must_use]
     pub const fn new_rgb_float(
         red: PercentageF32,
         green: PercentageF32,
@@ -117,7 +191,50 @@ impl Color<RgbFloat> {
             color: RgbFloat { red, green, blue },
         }
     }
+
+    /// Creates a new colour from RGB components in the range `0..=1`, failing
+    /// if any are out of range.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if a component is outside
+    /// of `0..=1`.
+    pub fn try_new_rgb_float(red: f32, green: f32, blue: f32) -> Result<Self, ColorError> {
+        Ok(Self::new_rgb_float(
+            PercentageF32::try_new(red)?,
+            PercentageF32::try_new(green)?,
+            PercentageF32::try_new(blue)?,
+        ))
+    }
 }
 
 #[cfg(test)]
-mod test {}
+mod tests {
+    use {
+        super::RgbFloat,
+        crate::models::color_model::test_utils::{
+            GenerateColour,
+            TestColour,
+            colour_model_tests,
+        },
+    };
+
+    impl GenerateColour<RgbFloat> for TestColour {
+        const TOLERANCE: f64 = 0.005;
+
+        fn generate(&self) -> RgbFloat {
+            RgbFloat {
+                red:   self.rgb_float.0,
+                green: self.rgb_float.1,
+                blue:  self.rgb_float.2,
+            }
+        }
+
+        fn components(colour: &RgbFloat) -> Vec<f64> {
+            [colour.red, colour.green, colour.blue]
+                .map(|c| f64::from(c.value()))
+                .to_vec()
+        }
+    }
+
+    colour_model_tests!(RgbFloat);
+}
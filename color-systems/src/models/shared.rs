@@ -1,41 +1,47 @@
 use crate::{
-    circle_degrees::CircleDegrees,
-    circle_degrees_wrapped,
+    advanced::{
+        ChromaticAdaptation,
+        D65_STANDARD_ILLUMINANT,
+        Matrix,
+        MatrixRow,
+        RgbXyzConverter,
+        TransferFunction,
+        apply_transformation,
+    },
+    circle_degrees_f32::CircleDegreesF32,
+    circle_degrees_f32_wrapped,
+    error::ColorError,
     models::color_model::ColorModel,
-    percentage_f32::PercentageF32,
+    percentage_f32::{
+        PercentageF32,
+        SaturatingPercentage,
+    },
 };
 
+/// Selects 8-bit RGB components from a model's percentage components.
 #[inline]
-pub fn get_rgb_using_float<const R: bool, const G: bool, const B: bool, C: ColorModel>(
+pub fn get_rgb_using_float<const R: bool, const G: bool, const B: bool, C: ColorModel + ?Sized>(
     colour: &C,
 ) -> (Option<u8>, Option<u8>, Option<u8>) {
     let (r_float, g_float, b_float) = colour.select_rgb_float::<R, G, B>();
 
     (
-        R.then(|| {
-            r_float
-                .map(PercentageF32::to_percent_of_u8)
-                .expect("Red value was not calculated")
-        }),
-        G.then(|| {
-            g_float
-                .map(PercentageF32::to_percent_of_u8)
-                .expect("Green value was not calculated")
-        }),
-        B.then(|| {
-            b_float
-                .map(PercentageF32::to_percent_of_u8)
-                .expect("Blue value was not calculated")
-        }),
+        r_float.map(PercentageF32::to_percent_of_u8),
+        g_float.map(PercentageF32::to_percent_of_u8),
+        b_float.map(PercentageF32::to_percent_of_u8),
     )
 }
 
+// Exact comparisons are intended, to find which component is the maximum and
+// to catch the extremes where saturation is undefined.
+/// Converts sRGB to HSV, or to HSL when `VL_IS_L` is set.
+#[allow(clippy::float_cmp)]
 pub fn rgb_float_to_hsvl<const H: bool, const S: bool, const VL: bool, const VL_IS_L: bool>(
     red: PercentageF32,
     green: PercentageF32,
     blue: PercentageF32,
 ) -> (
-    Option<CircleDegrees>,
+    Option<CircleDegreesF32>,
     Option<PercentageF32>,
     Option<PercentageF32>,
 ) {
@@ -48,24 +54,25 @@ pub fn rgb_float_to_hsvl<const H: bool, const S: bool, const VL: bool, const VL_
 
     let h = H.then(|| {
         if max == min {
-            circle_degrees_wrapped!(0)
+            circle_degrees_f32_wrapped!(0.)
         } else {
             let range = max - min;
 
-            let component = match max {
-                m if m == r_float => ((g_float - b_float) / range) % 6.,
-                m if m == g_float => (b_float - r_float) / range + 2.,
-                m if m == b_float => (r_float - g_float) / range + 4.,
-                _ => panic!("Max does not match any RGB component"),
+            let component = if max == r_float {
+                ((g_float - b_float) / range) % 6.
+            } else if max == g_float {
+                (b_float - r_float) / range + 2.
+            } else {
+                (r_float - g_float) / range + 4.
             };
 
-            circle_degrees_wrapped!(f32::round(component * 60.) as i16)
+            circle_degrees_f32_wrapped!(component * 60.)
         }
     });
 
     let vl = VL.then(|| {
         if VL_IS_L {
-            (max + min) / 2.
+            f32::midpoint(max, min)
         } else {
             max
         }
@@ -75,12 +82,12 @@ pub fn rgb_float_to_hsvl<const H: bool, const S: bool, const VL: bool, const VL_
         let diff = max - min;
 
         if VL_IS_L {
-            let l = vl.expect("Luminosity was not calculated");
+            let l = f32::midpoint(max, min);
 
             if l == 0. || l == 1. {
                 0.
             } else {
-                diff / (1. - f32::abs(2. * max - diff - 1.))
+                diff / (1. - f32::abs(2.0f32.mul_add(max, -diff) - 1.))
             }
         } else if max == 0. {
             0.
@@ -91,11 +98,12 @@ pub fn rgb_float_to_hsvl<const H: bool, const S: bool, const VL: bool, const VL_
 
     (
         h,
-        s.map(PercentageF32::new_or_panic),
-        vl.map(PercentageF32::new_or_panic),
+        s.map(PercentageF32::new_clamped),
+        vl.map(PercentageF32::new_clamped),
     )
 }
 
+/// Converts sRGB to CMYK.
 pub fn rgb_float_to_cmyk_float<const C: bool, const M: bool, const Y: bool, const K: bool>(
     red: PercentageF32,
     green: PercentageF32,
@@ -113,14 +121,19 @@ pub fn rgb_float_to_cmyk_float<const C: bool, const M: bool, const Y: bool, cons
     let k_inv = PercentageF32::max(red, PercentageF32::max(green, blue));
     let k = PercentageF32::MAX - k_inv;
 
+    // For pure black `k_inv` is zero, and the division saturates `0 / 0` to
+    // zero.
+    let f = |v| (SaturatingPercentage(PercentageF32::MAX) - v - k) / k_inv;
+
     (
-        C.then(|| (PercentageF32::MAX - red - k) / k_inv),
-        M.then(|| (PercentageF32::MAX - green - k) / k_inv),
-        Y.then(|| (PercentageF32::MAX - blue - k) / k_inv),
+        C.then(|| f(red).0),
+        M.then(|| f(green).0),
+        Y.then(|| f(blue).0),
         K.then_some(k),
     )
 }
 
+/// Converts CMYK to sRGB.
 pub fn cmyk_float_to_rgb_float<const R: bool, const G: bool, const B: bool>(
     cyan: PercentageF32,
     magenta: PercentageF32,
@@ -141,3 +154,206 @@ pub fn cmyk_float_to_rgb_float<const R: bool, const G: bool, const B: bool>(
         B.then(|| f(yellow)),
     )
 }
+
+/// Converts sRGB to XYZ, scaled so that white has a `Y` of `1`.
+pub fn rgb_float_to_xyz<const X: bool, const Y: bool, const Z: bool>(
+    red: PercentageF32,
+    green: PercentageF32,
+    blue: PercentageF32,
+) -> (Option<f64>, Option<f64>, Option<f64>) {
+    if const { !(X || Y || Z) } {
+        return (None, None, None);
+    }
+
+    let linear = [red, green, blue].map(|c| TransferFunction::Srgb.to_linear(f64::from(c.value())));
+    let [x, y, z] = RgbXyzConverter::SRGB_CONVERTER.rgb_to_xyz(&linear);
+
+    (X.then_some(x), Y.then_some(y), Z.then_some(z))
+}
+
+/// Converts XYZ to sRGB, clamping out of gamut components.
+#[allow(clippy::cast_possible_truncation)]
+pub fn xyz_to_rgb_float<const R: bool, const G: bool, const B: bool>(
+    x: f64,
+    y: f64,
+    z: f64,
+) -> (
+    Option<PercentageF32>,
+    Option<PercentageF32>,
+    Option<PercentageF32>,
+) {
+    if const { !(R || G || B) } {
+        return (None, None, None);
+    }
+
+    let [red, green, blue] = RgbXyzConverter::SRGB_CONVERTER
+        .xyz_to_rgb(&[x, y, z])
+        .map(|c| PercentageF32::new_clamped(TransferFunction::Srgb.to_encoded(c) as f32));
+
+    (R.then_some(red), G.then_some(green), B.then_some(blue))
+}
+
+/// Fails if any component is infinite or NaN.
+pub fn ensure_finite(components: &MatrixRow) -> Result<(), ColorError> {
+    match components.iter().find(|c| !c.is_finite()) {
+        Some(&c) => Err(ColorError::NotFinite(c)),
+        None => Ok(()),
+    }
+}
+
+/// CIE ϵ, the point where the CIELAB function becomes linear.
+const CIE_EPSILON: f64 = 216. / 24_389.;
+/// CIE κ, the slope of the linear part of the CIELAB function.
+const CIE_KAPPA: f64 = 24_389. / 27.;
+
+/// Converts XYZ to CIELAB relative to `reference_white`.
+pub fn xyz_to_lab(xyz: &MatrixRow, reference_white: &MatrixRow) -> MatrixRow {
+    let adapted =
+        ChromaticAdaptation::Bradford.adapt(xyz, &D65_STANDARD_ILLUMINANT, reference_white);
+
+    let [f_x, f_y, f_z] = [0, 1, 2].map(|i| {
+        let ratio = adapted[i] / (reference_white[i] / reference_white[1]);
+
+        if ratio > CIE_EPSILON {
+            ratio.cbrt()
+        } else {
+            CIE_KAPPA.mul_add(ratio, 16.) / 116.
+        }
+    });
+
+    [
+        116f64.mul_add(f_y, -16.),
+        500. * (f_x - f_y),
+        200. * (f_y - f_z),
+    ]
+}
+
+/// Converts CIELAB relative to `reference_white` to XYZ.
+pub fn lab_to_xyz(lab: &MatrixRow, reference_white: &MatrixRow) -> MatrixRow {
+    let [lightness, a, b] = *lab;
+
+    let f_y = (lightness + 16.) / 116.;
+    let f_x = a / 500. + f_y;
+    let f_z = f_y - b / 200.;
+
+    let from_f = |f: f64| {
+        let cubed = f.powi(3);
+
+        if cubed > CIE_EPSILON {
+            cubed
+        } else {
+            116f64.mul_add(f, -16.) / CIE_KAPPA
+        }
+    };
+
+    let ratios = [
+        from_f(f_x),
+        if lightness > CIE_KAPPA * CIE_EPSILON {
+            f_y.powi(3)
+        } else {
+            lightness / CIE_KAPPA
+        },
+        from_f(f_z),
+    ];
+
+    let adapted = [0, 1, 2].map(|i| ratios[i] * reference_white[i] / reference_white[1]);
+
+    ChromaticAdaptation::Bradford.adapt(&adapted, reference_white, &D65_STANDARD_ILLUMINANT)
+}
+
+/// Converts CIELAB to CIELCh(ab), with the hue in degrees.
+pub fn lab_to_lch(lab: &MatrixRow) -> MatrixRow {
+    let [lightness, a, b] = *lab;
+
+    [
+        lightness,
+        a.hypot(b),
+        b.atan2(a).to_degrees().rem_euclid(360.),
+    ]
+}
+
+/// Converts CIELCh(ab) to CIELAB.
+pub fn lch_to_lab(lch: &MatrixRow) -> MatrixRow {
+    let [lightness, chroma, hue] = *lch;
+    let (sin, cos) = hue.to_radians().sin_cos();
+
+    [lightness, chroma * cos, chroma * sin]
+}
+
+/// XYZ to the LMS cone responses used by Oklab.
+const XYZ_TO_OKLAB_LMS: Matrix = [
+    [
+        0.819_022_437_996_703,
+        0.361_906_260_052_890_4,
+        -0.128_873_781_520_987_9,
+    ],
+    [
+        0.032_983_653_932_388_5,
+        0.929_286_861_586_343_4,
+        0.036_144_666_350_642_4,
+    ],
+    [
+        0.048_177_189_359_624_2,
+        0.264_239_531_752_730_8,
+        0.633_547_828_469_430_9,
+    ],
+];
+
+/// The LMS cone responses used by Oklab to XYZ.
+const OKLAB_LMS_TO_XYZ: Matrix = [
+    [
+        1.226_879_875_845_924_3,
+        -0.557_814_994_460_217_1,
+        0.281_391_045_665_964_7,
+    ],
+    [
+        -0.040_575_745_214_800_8,
+        1.112_286_803_280_317,
+        -0.071_711_058_065_516_4,
+    ],
+    [
+        -0.076_372_936_674_660_1,
+        -0.421_493_332_402_243_2,
+        1.586_924_019_836_781_6,
+    ],
+];
+
+/// The non-linear LMS cone responses to Oklab.
+const OKLAB_LMS_TO_OKLAB: Matrix = [
+    [
+        0.210_454_268_309_314,
+        0.793_617_774_702_305_4,
+        -0.004_072_043_011_619_3,
+    ],
+    [
+        1.977_998_532_431_168_4,
+        -2.428_592_242_048_58,
+        0.450_593_709_617_411,
+    ],
+    [
+        0.025_904_042_465_547_8,
+        0.782_771_712_457_529_6,
+        -0.808_675_754_923_077_4,
+    ],
+];
+
+/// Oklab to the non-linear LMS cone responses.
+const OKLAB_TO_OKLAB_LMS: Matrix = [
+    [1., 0.396_337_777_376_174_9, 0.215_803_757_309_913_6],
+    [1., -0.105_561_345_815_658_6, -0.063_854_172_825_813_3],
+    [1., -0.089_484_177_529_811_9, -1.291_485_548_019_409_2],
+];
+
+/// Converts XYZ to Oklab.
+pub fn xyz_to_oklab(xyz: &MatrixRow) -> MatrixRow {
+    let lms = apply_transformation(xyz, &XYZ_TO_OKLAB_LMS).map(f64::cbrt);
+
+    apply_transformation(&lms, &OKLAB_LMS_TO_OKLAB)
+}
+
+/// Converts Oklab to XYZ.
+pub fn oklab_to_xyz(oklab: &MatrixRow) -> MatrixRow {
+    let lms = apply_transformation(oklab, &OKLAB_TO_OKLAB_LMS).map(|c| c.powi(3));
+
+    apply_transformation(&lms, &OKLAB_LMS_TO_XYZ)
+}
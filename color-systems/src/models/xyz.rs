@@ -0,0 +1,333 @@
+use {
+    crate::{
+        advanced::{
+            ChromaticAdaptation,
+            D65_STANDARD_ILLUMINANT,
+            MatrixRow,
+        },
+        color::Color,
+        css::{
+            CssFormatter,
+            CssValue,
+            ToCss,
+        },
+        error::ColorError,
+        interpolation::{
+            HueInterpolation,
+            Interpolate,
+            lerp,
+        },
+        models::{
+            color_model::{
+                ColorModel,
+                FromColorModel,
+                OpaqueColorModel,
+            },
+            shared::{
+                ensure_finite,
+                xyz_to_rgb_float,
+            },
+        },
+        percentage_f32::PercentageF32,
+    },
+    std::fmt::{
+        self,
+        Write,
+    },
+};
+
+/// CIE 1931 XYZ tristimulus values, relative to the D65 white point with a
+/// luminance (`Y`) of `1`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+    /// X.
+    x: f64,
+    /// Y, the luminance.
+    y: f64,
+    /// Z.
+    z: f64,
+}
+
+impl ColorModel for Xyz {
+    #[inline]
+    fn select_rgb_float<const R: bool, const G: bool, const B: bool>(
+        &self,
+    ) -> (
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+        Option<PercentageF32>,
+    ) {
+        xyz_to_rgb_float::<R, G, B>(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn select_xyz<const X: bool, const Y: bool, const Z: bool>(
+        &self,
+    ) -> (Option<f64>, Option<f64>, Option<f64>) {
+        (
+            X.then_some(self.x),
+            Y.then_some(self.y),
+            Z.then_some(self.z),
+        )
+    }
+}
+
+impl OpaqueColorModel for Xyz {}
+
+impl ToCss for Xyz {
+    fn write_css(
+        &self,
+        formatter: &CssFormatter,
+        alpha: Option<PercentageF32>,
+        out: &mut dyn Write,
+    ) -> fmt::Result {
+        formatter.write_function(
+            out,
+            "color",
+            &[
+                CssValue::Keyword("xyz-d65"),
+                CssValue::Number(self.x),
+                CssValue::Number(self.y),
+                CssValue::Number(self.z),
+            ],
+            alpha,
+            false,
+        )
+    }
+}
+
+impl Interpolate for Xyz {
+    #[inline]
+    fn interpolate(&self, other: &Self, t: f32, _hue: HueInterpolation) -> Self {
+        Self {
+            x: lerp(self.x, other.x, t),
+            y: lerp(self.y, other.y, t),
+            z: lerp(self.z, other.z, t),
+        }
+    }
+}
+
+impl FromColorModel for Xyz {
+    #[inline]
+    fn try_from_color<C: ColorModel>(color: &Color<C>) -> Result<Color<Self>, ColorError> {
+        color.try_to_xyz()
+    }
+}
+
+impl<C: ColorModel> Color<C> {
+    /// Converts the colour to CIE XYZ relative to D65.
+    ///
+    /// # Panics
+    /// Panics if the model cannot provide the components; see
+    /// [`Self::try_to_xyz`].
+    #[inline]
+    pub fn to_xyz(&self) -> Color<Xyz> {
+        self.try_to_xyz().expect("XYZ values were not returned")
+    }
+
+    /// Converts the colour to CIE XYZ relative to D65.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub fn try_to_xyz(&self) -> Result<Color<Xyz>, ColorError> {
+        let [x, y, z] = self.try_xyz_tristimulus()?;
+
+        Ok(Color::new_xyz(x, y, z))
+    }
+
+    /// Returns the XYZ tristimulus values adapted to another white point,
+    /// expressed like [`D65_STANDARD_ILLUMINANT`].
+    #[inline]
+    pub fn to_xyz_adapted(
+        &self,
+        white_point: &MatrixRow,
+        method: ChromaticAdaptation,
+    ) -> MatrixRow {
+        method.adapt(
+            &self.xyz_tristimulus(),
+            &D65_STANDARD_ILLUMINANT,
+            white_point,
+        )
+    }
+
+    /// The colour as XYZ, scaled so that white has a `Y` of `1`.
+    #[inline]
+    pub(crate) fn xyz_tristimulus(&self) -> MatrixRow {
+        self.try_xyz_tristimulus()
+            .expect("XYZ values were not returned")
+    }
+
+    /// The colour as XYZ, scaled so that white has a `Y` of `1`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::ComponentUnavailable`] if the model cannot provide
+    /// the components.
+    #[inline]
+    pub(crate) fn try_xyz_tristimulus(&self) -> Result<MatrixRow, ColorError> {
+        match self.color.select_xyz::<true, true, true>() {
+            (Some(x), Some(y), Some(z)) => Ok([x, y, z]),
+            _ => Err(ColorError::ComponentUnavailable("XYZ")),
+        }
+    }
+
+    /// Converts the colour to CIE XYZ relative to D65, consuming it.
+    #[inline]
+    pub fn into_xyz(self) -> Color<Xyz> {
+        self.to_xyz()
+    }
+}
+
+impl Color<Xyz> {
+    /// Creates a colour from CIE XYZ tristimulus values relative to D65, where
+    /// white has a `Y` of `1`.
+    #[inline]
+    #[must_use]
+    pub const fn new_xyz(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            color: Xyz { x, y, z },
+        }
+    }
+
+    /// Creates a new colour from XYZ tristimulus values, failing if any are
+    /// infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component is infinite or NaN.
+    #[inline]
+    pub fn try_new_xyz(x: f64, y: f64, z: f64) -> Result<Self, ColorError> {
+        ensure_finite(&[x, y, z])?;
+
+        Ok(Self::new_xyz(x, y, z))
+    }
+
+    /// Creates a new colour from XYZ tristimulus values measured under another
+    /// white point, adapting them to D65.
+    #[inline]
+    #[must_use]
+    pub fn new_xyz_adapted(
+        xyz: &MatrixRow,
+        white_point: &MatrixRow,
+        method: ChromaticAdaptation,
+    ) -> Self {
+        let [x, y, z] = method.adapt(xyz, white_point, &D65_STANDARD_ILLUMINANT);
+
+        Self::new_xyz(x, y, z)
+    }
+
+    /// Creates a new colour from XYZ tristimulus values measured under another
+    /// white point, failing if any value or the white point is infinite or NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::NotFinite`] if a component or the white point is
+    /// infinite or NaN.
+    #[inline]
+    pub fn try_new_xyz_adapted(
+        xyz: &MatrixRow,
+        white_point: &MatrixRow,
+        method: ChromaticAdaptation,
+    ) -> Result<Self, ColorError> {
+        ensure_finite(xyz)?;
+        ensure_finite(white_point)?;
+
+        Ok(Self::new_xyz_adapted(xyz, white_point, method))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Xyz,
+        crate::{
+            advanced::{
+                ChromaticAdaptation,
+                D50_STANDARD_ILLUMINANT,
+            },
+            color::Color,
+            models::color_model::test_utils::{
+                GenerateColour,
+                TestColour,
+                assert_close,
+                colour_model_tests,
+            },
+        },
+    };
+
+    impl GenerateColour<Xyz> for TestColour {
+        const TOLERANCE: f64 = 0.001;
+
+        fn generate(&self) -> Xyz {
+            Xyz {
+                x: self.xyz.0,
+                y: self.xyz.1,
+                z: self.xyz.2,
+            }
+        }
+
+        fn components(colour: &Xyz) -> Vec<f64> {
+            vec![colour.x, colour.y, colour.z]
+        }
+    }
+
+    colour_model_tests!(Xyz);
+
+    #[test]
+    fn test_srgb_white_is_d65() {
+        let white = Color::new_rgb(255, 255, 255).to_xyz();
+
+        assert_close(white.x(), 0.950_47, 1e-4);
+        assert_close(white.y(), 1., 1e-4);
+        assert_close(white.z(), 1.088_83, 1e-4);
+    }
+
+    #[test]
+    fn test_transfer_function_is_applied() {
+        let grey = Color::new_rgb(128, 128, 128).to_xyz();
+
+        assert_close(grey.y(), 0.215_861, 1e-4);
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        for (r, g, b) in [
+            (0, 0, 0),
+            (255, 255, 255),
+            (255, 0, 0),
+            (1, 7, 20),
+            (161, 131, 114),
+            (92, 17, 73),
+            (222, 220, 120),
+        ] {
+            let round_trip = Color::new_rgb(r, g, b).to_xyz().to_rgb();
+
+            assert_eq!(
+                (round_trip.red(), round_trip.green(), round_trip.blue()),
+                (r, g, b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_adapted_white_point() {
+        let white = Color::new_rgb(255, 255, 255);
+        let adapted = white.to_xyz_adapted(&D50_STANDARD_ILLUMINANT, ChromaticAdaptation::Bradford);
+
+        assert_close(adapted[0], 0.964_22, 1e-4);
+        assert_close(adapted[1], 1., 1e-4);
+        assert_close(adapted[2], 0.825_21, 1e-4);
+
+        let round_trip = Color::new_xyz_adapted(
+            &adapted,
+            &D50_STANDARD_ILLUMINANT,
+            ChromaticAdaptation::Bradford,
+        )
+        .to_rgb();
+
+        assert_eq!(
+            (round_trip.red(), round_trip.green(), round_trip.blue()),
+            (255, 255, 255)
+        );
+    }
+}
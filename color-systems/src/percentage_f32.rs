@@ -1,74 +1,198 @@
-use std::ops::{
-    Add,
-    Div,
-    Mul,
-    Sub,
+use {
+    crate::error::ColorError,
+    std::ops::{
+        Add,
+        Div,
+        Mul,
+        Sub,
+    },
 };
 
+/// Creates a [`PercentageF32`](crate::PercentageF32), panicking if the value
+/// is outside of `0..=1`.
 #[macro_export]
 macro_rules! p32 {
     ($value:expr) => {
-        $crate::percentage_f32::PercentageF32::new_or_panic($value)
+        $crate::PercentageF32::new_or_panic($value)
     };
 }
 
+/// Creates a [`PercentageF32`](crate::PercentageF32), returning a
+/// [`ColorError`](crate::ColorError) if the value is outside of `0..=1`.
 #[macro_export]
 macro_rules! try_percentage_f32 {
     ($value:expr) => {
-        $crate::percentage_f32::PercentageF32::try_new($value)
+        $crate::PercentageF32::try_new($value)
     };
 }
 
+/// A value in the range `0..=1`, such as a colour component or an alpha.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "f32", into = "f32")
+)]
 pub struct PercentageF32(f32);
 
 impl PercentageF32 {
+    /// `1`, or 100%.
     pub const MAX: Self = Self(1.);
+    /// `0`, or 0%.
     pub const MIN: Self = Self(0.);
 
+    /// Creates a new percentage.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if the value is outside of
+    /// `0..=1` or is NaN.
     #[inline]
-    pub fn try_new(with: f32) -> Option<Self> {
+    pub fn try_new(with: f32) -> Result<Self, ColorError> {
         if (0f32..=1f32).contains(&with) {
-            Some(Self(with))
+            Ok(Self(with))
         } else {
-            None
+            Err(ColorError::PercentageOutOfRange(with))
         }
     }
 
+    /// Creates a new percentage.
+    ///
+    /// # Panics
+    /// Panics if the value is outside of `0..=1` or is NaN.
     #[inline]
+    #[must_use]
     pub fn new_or_panic(with: f32) -> Self {
-        Self::try_new(with).unwrap_or_else(|| {
+        Self::try_new(with).unwrap_or_else(|_| {
             panic!("Attempted to create new PercentageF32 using invalid value ({with})")
         })
     }
 
+    /// Creates a new percentage, clamping values outside of `0..=1` to the
+    /// nearest bound. NaN is treated as `0`.
     #[inline]
+    #[must_use]
+    pub const fn new_clamped(with: f32) -> Self {
+        if with.is_nan() {
+            Self::MIN
+        } else {
+            Self(with.clamp(0., 1.))
+        }
+    }
+
+    /// Creates a percentage from an 8-bit value, where `255` is `1`.
+    #[inline]
+    #[must_use]
     pub fn from_percent_of_u8(from: u8) -> Self {
-        Self(from as f32 / u8::MAX as f32)
+        Self(f32::from(from) / f32::from(u8::MAX))
     }
 
+    /// The percentage as a value in the range `0..=1`.
     #[inline]
+    #[must_use]
     pub const fn value(&self) -> f32 {
         self.0
     }
 
+    // The product is always in the range `0..=255`.
+    /// The percentage scaled to an 8-bit value, where `1` is `255`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     #[inline]
+    #[must_use]
     pub fn as_percent_of_u8(&self) -> u8 {
-        f32::round(self.0 * u8::MAX as f32) as u8
+        f32::round(self.0 * f32::from(u8::MAX)) as u8
     }
 
+    // The product is always in the range `0..=255`.
+    /// The percentage scaled to an 8-bit value, where `1` is `255`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     #[inline]
+    #[must_use]
     pub fn to_percent_of_u8(self) -> u8 {
-        f32::round(self.0 * u8::MAX as f32) as u8
+        f32::round(self.0 * f32::from(u8::MAX)) as u8
+    }
+
+    /// Adds two percentages, failing if the result is greater than `1`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if the result is outside of
+    /// `0..=1`.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ColorError> {
+        Self::try_new(self.0 + rhs.0)
+    }
+
+    /// Subtracts two percentages, failing if the result is less than `0`.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if the result is outside of
+    /// `0..=1`.
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ColorError> {
+        Self::try_new(self.0 - rhs.0)
     }
 
+    /// Multiplies two percentages. The result is always in range unless either
+    /// operand is NaN.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if the result is outside of
+    /// `0..=1`.
     #[inline]
-    pub fn max(self, other: Self) -> Self {
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ColorError> {
+        Self::try_new(self.0 * rhs.0)
+    }
+
+    /// Divides two percentages, failing if the result is greater than `1` or
+    /// undefined.
+    ///
+    /// # Errors
+    /// Returns [`ColorError::PercentageOutOfRange`] if the result is outside of
+    /// `0..=1`.
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ColorError> {
+        Self::try_new(self.0 / rhs.0)
+    }
+
+    /// Adds two percentages, clamping the result to `1`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0 + rhs.0)
+    }
+
+    /// Subtracts two percentages, clamping the result to `0`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0 - rhs.0)
+    }
+
+    /// Multiplies two percentages, clamping the result to `0..=1`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0 * rhs.0)
+    }
+
+    /// Divides two percentages, clamping the result to `0..=1`. Dividing by
+    /// zero gives `1`, unless the dividend is also zero, which gives `0`.
+    #[inline]
+    #[must_use]
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        Self::new_clamped(self.0 / rhs.0)
+    }
+
+    /// The larger of two percentages.
+    #[inline]
+    #[must_use]
+    pub const fn max(self, other: Self) -> Self {
         Self(f32::max(self.0, other.0))
     }
 
+    /// The smaller of two percentages.
     #[inline]
-    pub fn min(self, other: Self) -> Self {
+    #[must_use]
+    pub const fn min(self, other: Self) -> Self {
         Self(f32::min(self.0, other.0))
     }
 }
@@ -78,10 +202,8 @@ impl Add for PercentageF32 {
 
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        let value = self.0 + rhs.0;
-
-        Self::try_new(value).unwrap_or_else(|| {
-            panic!("Add operation resulted in a value outside of valid range ({value})")
+        self.checked_add(rhs).unwrap_or_else(|error| {
+            panic!("Add operation resulted in a value outside of valid range ({error})")
         })
     }
 }
@@ -91,10 +213,8 @@ impl Div for PercentageF32 {
 
     #[inline]
     fn div(self, rhs: Self) -> Self::Output {
-        let value = self.0 / rhs.0;
-
-        Self::try_new(value).unwrap_or_else(|| {
-            panic!("Div operation resulted in a value outside of valid range ({value})")
+        self.checked_div(rhs).unwrap_or_else(|error| {
+            panic!("Div operation resulted in a value outside of valid range ({error})")
         })
     }
 }
@@ -104,10 +224,8 @@ impl Mul for PercentageF32 {
 
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        let value = self.0 * rhs.0;
-
-        Self::try_new(value).unwrap_or_else(|| {
-            panic!("Mul operation resulted in a value outside of valid range ({value})")
+        self.checked_mul(rhs).unwrap_or_else(|error| {
+            panic!("Mul operation resulted in a value outside of valid range ({error})")
         })
     }
 }
@@ -117,20 +235,18 @@ impl Sub for PercentageF32 {
 
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        let value = self.0 - rhs.0;
-
-        Self::try_new(value).unwrap_or_else(|| {
-            panic!("Sub operation resulted in a value outside of valid range ({value})")
+        self.checked_sub(rhs).unwrap_or_else(|error| {
+            panic!("Sub operation resulted in a value outside of valid range ({error})")
         })
     }
 }
 
 impl TryFrom<f32> for PercentageF32 {
-    type Error = f32;
+    type Error = ColorError;
 
     #[inline]
     fn try_from(value: f32) -> Result<Self, Self::Error> {
-        Self::try_new(value).ok_or(value)
+        Self::try_new(value)
     }
 }
 
@@ -140,3 +256,154 @@ impl From<PercentageF32> for f32 {
         value.value()
     }
 }
+
+/// A percentage whose arithmetic clamps out-of-range results to `0..=1`,
+/// rather than panicking.
+#[derive(Clone, Copy, Debug)]
+pub struct SaturatingPercentage(pub PercentageF32);
+
+/// A percentage whose arithmetic records the first out-of-range result,
+/// rather than panicking. Use [`CheckedPercentage::get`] to retrieve the
+/// outcome of a chain of operations.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckedPercentage(Result<PercentageF32, ColorError>);
+
+impl CheckedPercentage {
+    /// Starts a chain of checked operations from `with`.
+    #[inline]
+    #[must_use]
+    pub const fn new(with: PercentageF32) -> Self {
+        Self(Ok(with))
+    }
+
+    /// Returns the result of the operations, or the first error encountered.
+    ///
+    /// # Errors
+    /// Returns the first [`ColorError`] produced by the chain of operations.
+    #[inline]
+    pub const fn get(self) -> Result<PercentageF32, ColorError> {
+        self.0
+    }
+
+    /// Applies `operation` if no earlier operation has failed.
+    #[inline]
+    fn apply(
+        self,
+        rhs: PercentageF32,
+        op: fn(PercentageF32, PercentageF32) -> Result<PercentageF32, ColorError>,
+    ) -> Self {
+        Self(self.0.and_then(|lhs| op(lhs, rhs)))
+    }
+}
+
+/// Implements the arithmetic operators for the saturating and checked wrappers.
+macro_rules! impl_wrapper_ops {
+    ($($trait:ident::$fn:ident => $saturating:ident, $checked:ident;)*) => {
+        $(
+            impl $trait for SaturatingPercentage {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: Self) -> Self::Output {
+                    Self(self.0.$saturating(rhs.0))
+                }
+            }
+
+            impl $trait<PercentageF32> for SaturatingPercentage {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: PercentageF32) -> Self::Output {
+                    Self(self.0.$saturating(rhs))
+                }
+            }
+
+            impl $trait<PercentageF32> for CheckedPercentage {
+                type Output = Self;
+
+                #[inline]
+                fn $fn(self, rhs: PercentageF32) -> Self::Output {
+                    self.apply(rhs, PercentageF32::$checked)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapper_ops! {
+    Add::add => saturating_add, checked_add;
+    Sub::sub => saturating_sub, checked_sub;
+    Mul::mul => saturating_mul, checked_mul;
+    Div::div => saturating_div, checked_div;
+}
+
+impl From<PercentageF32> for SaturatingPercentage {
+    #[inline]
+    fn from(value: PercentageF32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PercentageF32> for CheckedPercentage {
+    #[inline]
+    fn from(value: PercentageF32) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::{
+        CheckedPercentage,
+        PercentageF32,
+        SaturatingPercentage,
+    };
+
+    #[test]
+    fn test_new_clamped() {
+        assert_eq!(PercentageF32::new_clamped(-0.5).value(), 0.);
+        assert_eq!(PercentageF32::new_clamped(1.5).value(), 1.);
+        assert_eq!(PercentageF32::new_clamped(f32::NAN).value(), 0.);
+        assert_eq!(PercentageF32::new_clamped(f32::INFINITY).value(), 1.);
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        let half = PercentageF32::new_or_panic(0.5);
+        let three_quarters = PercentageF32::new_or_panic(0.75);
+
+        assert_eq!(half.saturating_add(three_quarters).value(), 1.);
+        assert_eq!(half.saturating_sub(three_quarters).value(), 0.);
+        assert_eq!(three_quarters.saturating_div(half).value(), 1.);
+        assert_eq!(half.saturating_div(PercentageF32::MIN).value(), 1.);
+        assert_eq!(
+            PercentageF32::MIN
+                .saturating_div(PercentageF32::MIN)
+                .value(),
+            0.
+        );
+
+        let SaturatingPercentage(result) = SaturatingPercentage(half) - three_quarters + half;
+
+        assert_eq!(result.value(), 0.5);
+    }
+
+    #[test]
+    fn test_checked_wrapper_keeps_first_error() {
+        let half = PercentageF32::new_or_panic(0.5);
+        let three_quarters = PercentageF32::new_or_panic(0.75);
+
+        assert_eq!(
+            (CheckedPercentage::new(three_quarters) - half)
+                .get()
+                .map(|p| p.value()),
+            Ok(0.25)
+        );
+        assert!(
+            (CheckedPercentage::new(half) - three_quarters + half)
+                .get()
+                .is_err()
+        );
+    }
+}
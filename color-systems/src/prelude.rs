@@ -0,0 +1,37 @@
+//! The types needed by most code that uses colours.
+//!
+//! ```
+//! use color_systems::prelude::*;
+//! ```
+
+pub use crate::{
+    AnyColor,
+    CircleDegreesF32,
+    Color,
+    ColorError,
+    ColorModel,
+    FromColorModel,
+    OpaqueColorModel,
+    PercentageF32,
+    circle_degrees_f32,
+    css::{
+        CssFormatter,
+        ToCss,
+    },
+    models::{
+        Alpha,
+        Cmyk,
+        CmykFloat,
+        Hsl,
+        Hsv,
+        Lab,
+        Lch,
+        LinearRgb,
+        Oklab,
+        Oklch,
+        Rgb,
+        RgbFloat,
+        Xyz,
+    },
+    p32,
+};
@@ -0,0 +1,162 @@
+//! Alternative serde representations for colours.
+//!
+//! By default a [`Color`](crate::Color) is (de)serialized as its component
+//! struct, such as `{"red": 26, "green": 43, "blue": 60}`. The modules here can
+//! be selected with `#[serde(with = "...")]` to use a string instead.
+
+/// Represents a `Color<Rgb>` as a `#rrggbb` string. Any of the forms accepted
+/// by [`Color::from_hex_str`](crate::Color::from_hex_str) can be deserialized.
+pub mod hex {
+    use {
+        crate::{
+            color::Color,
+            models::Rgb,
+        },
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+            de::Error,
+        },
+    };
+
+    /// Writes the colour as a lowercase `#rrggbb` string.
+    ///
+    /// # Errors
+    /// Returns any error from the serializer.
+    #[inline]
+    pub fn serialize<S: Serializer>(color: &Color<Rgb>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_hex_string())
+    }
+
+    /// Reads a hex colour string.
+    ///
+    /// # Errors
+    /// Fails if the value is not a string of 3, 4, 6 or 8 hex digits, or if it
+    /// has an alpha that is not fully opaque.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color<Rgb>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+
+        Color::from_hex_str(&hex).map_err(Error::custom)
+    }
+}
+
+/// Represents a colour as a CSS string, written with the default
+/// [`CssFormatter`](crate::css::CssFormatter) and read with the colour's
+/// [`FromStr`](std::str::FromStr) implementation.
+pub mod css {
+    use {
+        crate::{
+            color::Color,
+            css::ToCss,
+            models::color_model::ColorModel,
+        },
+        serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+            de::Error,
+        },
+        std::{
+            fmt::Display,
+            str::FromStr,
+        },
+    };
+
+    /// Writes the colour as a CSS string.
+    ///
+    /// # Errors
+    /// Returns any error from the serializer.
+    #[inline]
+    pub fn serialize<C, S>(color: &Color<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: ColorModel + ToCss,
+        S: Serializer,
+    {
+        serializer.collect_str(color)
+    }
+
+    /// Reads a CSS colour string.
+    ///
+    /// # Errors
+    /// Fails if the value is not a string, or if it cannot be parsed as `T`.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let css = String::deserialize(deserializer)?;
+
+        css.parse().map_err(Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            color::Color,
+            models::{
+                Alpha,
+                Hsl,
+                Rgb,
+            },
+            percentage_f32::PercentageF32,
+        },
+        serde::{
+            Deserialize,
+            Serialize,
+        },
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct Theme {
+        #[serde(with = "super::hex")]
+        background: Color<Rgb>,
+        #[serde(with = "super::css")]
+        overlay:    Color<Alpha<Rgb>>,
+        accent:     Color<Hsl>,
+    }
+
+    #[test]
+    fn test_representations() {
+        let json = r##"{
+            "background": "#1a2b3c",
+            "overlay": "rgb(0 0 0 / 50%)",
+            "accent": {"hue": 210.0, "saturation": 0.5, "luminosity": 0.4}
+        }"##;
+        let theme: Theme = serde_json::from_str(json).expect("Valid theme was rejected");
+
+        assert_eq!(theme.background.to_hex_u32(), 0x1a_2b3c);
+        assert_eq!(theme.overlay.alpha().to_percent_of_u8(), 128);
+        assert_eq!(theme.accent.to_string(), "hsl(210deg 50% 40%)");
+
+        assert_eq!(
+            serde_json::to_string(&theme).expect("Theme was not serialized"),
+            r##"{"background":"#1a2b3c","overlay":"#00000080","accent":{"hue":210.0,"saturation":0.5,"luminosity":0.4}}"##
+        );
+    }
+
+    #[test]
+    fn test_range_is_validated() {
+        assert!(serde_json::from_str::<PercentageF32>("0.5").is_ok());
+        assert!(serde_json::from_str::<PercentageF32>("1.5").is_err());
+        assert!(
+            serde_json::from_str::<Color<Hsl>>(
+                r#"{"hue": 360.0, "saturation": 0.5, "luminosity": 0.4}"#
+            )
+            .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Color<Rgb>>(r#"{"red": 256, "green": 0, "blue": 0}"#).is_err()
+        );
+
+        let rgb: Color<Rgb> = serde_json::from_str(r#"{"red": 26, "green": 43, "blue": 60}"#)
+            .expect("Valid RGB was rejected");
+
+        assert_eq!(rgb.to_hex_u32(), 0x1a_2b3c);
+    }
+}
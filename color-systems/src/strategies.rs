@@ -0,0 +1,239 @@
+//! [`proptest`] strategies that generate colours, for property testing code
+//! that uses this crate.
+//!
+//! Models that can represent colours outside of sRGB, such as CIELAB and
+//! Oklch, are generated from sRGB colours, so every generated colour is in the
+//! sRGB gamut and survives a round trip through any other model.
+//!
+//! ```
+//! use {
+//!     color_systems::strategies,
+//!     proptest::{
+//!         prop_assert,
+//!         test_runner::TestRunner,
+//!     },
+//! };
+//!
+//! TestRunner::default()
+//!     .run(&strategies::oklch(), |colour| {
+//!         prop_assert!(colour.luminosity().value() <= 1.);
+//!
+//!         Ok(())
+//!     })
+//!     .expect("Luminosity was not a percentage");
+//! ```
+
+use {
+    crate::{
+        circle_degrees_f32::CircleDegreesF32,
+        color::Color,
+        models::{
+            Alpha,
+            AnyColor,
+            Cmyk,
+            CmykFloat,
+            Hsl,
+            Hsv,
+            Lab,
+            Lch,
+            LinearRgb,
+            Oklab,
+            Oklch,
+            Rgb,
+            RgbFloat,
+            Xyz,
+            color_model::OpaqueColorModel,
+        },
+        percentage_f32::PercentageF32,
+    },
+    proptest::{
+        prelude::{
+            Strategy,
+            any,
+            prop_oneof,
+        },
+        strategy::BoxedStrategy,
+    },
+    std::fmt::Debug,
+};
+
+/// Any percentage in `0..=1`.
+pub fn percentage() -> impl Strategy<Value = PercentageF32> {
+    (0f32..=1.).prop_map(PercentageF32::new_clamped)
+}
+
+/// Any angle in `0..360`.
+pub fn hue() -> impl Strategy<Value = CircleDegreesF32> {
+    (0f32..360.).prop_map(CircleDegreesF32::new_wrapped)
+}
+
+/// Any 8-bit sRGB colour.
+pub fn rgb() -> impl Strategy<Value = Color<Rgb>> {
+    any::<(u8, u8, u8)>().prop_map(|(red, green, blue)| Color::new_rgb(red, green, blue))
+}
+
+/// Any sRGB colour with percentage components.
+pub fn rgb_float() -> impl Strategy<Value = Color<RgbFloat>> {
+    (percentage(), percentage(), percentage())
+        .prop_map(|(red, green, blue)| Color::new_rgb_float(red, green, blue))
+}
+
+/// Any linear-light sRGB colour in the sRGB gamut.
+pub fn linear_rgb() -> impl Strategy<Value = Color<LinearRgb>> {
+    rgb_float().prop_map(|colour| colour.to_linear_rgb())
+}
+
+/// Any 8-bit CMYK colour.
+pub fn cmyk() -> impl Strategy<Value = Color<Cmyk>> {
+    any::<(u8, u8, u8, u8)>().prop_map(|(cyan, magenta, yellow, key_black)| {
+        Color::new_cmyk(cyan, magenta, yellow, key_black)
+    })
+}
+
+/// Any CMYK colour with percentage components.
+pub fn cmyk_float() -> impl Strategy<Value = Color<CmykFloat>> {
+    (percentage(), percentage(), percentage(), percentage()).prop_map(
+        |(cyan, magenta, yellow, key_black)| {
+            Color::new_cmyk_float(cyan, magenta, yellow, key_black)
+        },
+    )
+}
+
+/// Any HSL colour.
+pub fn hsl() -> impl Strategy<Value = Color<Hsl>> {
+    (hue(), percentage(), percentage())
+        .prop_map(|(hue, saturation, luminosity)| Color::new_hsl(hue, saturation, luminosity))
+}
+
+/// Any HSV colour.
+pub fn hsv() -> impl Strategy<Value = Color<Hsv>> {
+    (hue(), percentage(), percentage())
+        .prop_map(|(hue, saturation, value)| Color::new_hsv(hue, saturation, value))
+}
+
+/// Any CIE XYZ colour in the sRGB gamut.
+pub fn xyz() -> impl Strategy<Value = Color<Xyz>> {
+    rgb_float().prop_map(|colour| colour.to_xyz())
+}
+
+/// Any CIELAB colour in the sRGB gamut, relative to D65.
+pub fn lab() -> impl Strategy<Value = Color<Lab>> {
+    rgb_float().prop_map(|colour| colour.to_lab())
+}
+
+/// Any CIELCh(ab) colour in the sRGB gamut, relative to D65.
+pub fn lch() -> impl Strategy<Value = Color<Lch>> {
+    rgb_float().prop_map(|colour| colour.to_lch())
+}
+
+/// Any Oklab colour in the sRGB gamut.
+pub fn oklab() -> impl Strategy<Value = Color<Oklab>> {
+    rgb_float().prop_map(|colour| colour.to_oklab())
+}
+
+/// Any Oklch colour in the sRGB gamut.
+pub fn oklch() -> impl Strategy<Value = Color<Oklch>> {
+    rgb_float().prop_map(|colour| colour.to_oklch())
+}
+
+/// A colour from any of the models in this crate.
+pub fn any_color() -> BoxedStrategy<Color<AnyColor>> {
+    prop_oneof![
+        rgb().prop_map(Color::from),
+        rgb_float().prop_map(Color::from),
+        linear_rgb().prop_map(Color::from),
+        cmyk().prop_map(Color::from),
+        cmyk_float().prop_map(Color::from),
+        hsl().prop_map(Color::from),
+        hsv().prop_map(Color::from),
+        xyz().prop_map(Color::from),
+        lab().prop_map(Color::from),
+        lch().prop_map(Color::from),
+        oklab().prop_map(Color::from),
+        oklch().prop_map(Color::from),
+    ]
+    .boxed()
+}
+
+/// Adds any alpha to the colours generated by `colours`.
+pub fn with_alpha<C: OpaqueColorModel + Debug>(
+    colours: impl Strategy<Value = Color<C>>,
+) -> impl Strategy<Value = Color<Alpha<C>>> {
+    (colours, percentage()).prop_map(|(colour, alpha)| colour.with_alpha(alpha))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{
+            any_color,
+            rgb_float,
+            with_alpha,
+        },
+        crate::{
+            color::Color,
+            models::{
+                Alpha,
+                AnyColor,
+                CmykFloat,
+                Hsl,
+                Hsv,
+                Lab,
+                Lch,
+                LinearRgb,
+                Oklab,
+                Oklch,
+                RgbFloat,
+                Xyz,
+                color_model::ColorModel,
+            },
+        },
+        proptest::prelude::*,
+    };
+
+    fn rgb_float_components<C: ColorModel>(colour: &Color<C>) -> [f32; 3] {
+        [
+            colour.red_float().value(),
+            colour.green_float().value(),
+            colour.blue_float().value(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_float_round_trip(colour in rgb_float()) {
+            let expected = rgb_float_components(&colour);
+
+            for round_trip in [
+                colour.convert::<LinearRgb>().convert::<RgbFloat>(),
+                colour.convert::<CmykFloat>().convert::<RgbFloat>(),
+                colour.convert::<Hsl>().convert::<RgbFloat>(),
+                colour.convert::<Hsv>().convert::<RgbFloat>(),
+                colour.convert::<Xyz>().convert::<RgbFloat>(),
+                colour.convert::<Lab>().convert::<RgbFloat>(),
+                colour.convert::<Lch>().convert::<RgbFloat>(),
+                colour.convert::<Oklab>().convert::<RgbFloat>(),
+                colour.convert::<Oklch>().convert::<RgbFloat>(),
+            ] {
+                let actual = rgb_float_components(&round_trip);
+
+                for (actual, expected) in actual.into_iter().zip(expected) {
+                    prop_assert!((actual - expected).abs() < 1e-4, "{expected} became {actual}");
+                }
+            }
+        }
+
+        #[test]
+        fn test_css_round_trip(colour in with_alpha(any_color())) {
+            let parsed = colour
+                .to_string()
+                .parse::<Color<Alpha<AnyColor>>>()
+                .expect("Serialized colour could not be parsed");
+
+            prop_assert!(parsed.red().abs_diff(colour.red()) <= 1);
+            prop_assert!(parsed.green().abs_diff(colour.green()) <= 1);
+            prop_assert!(parsed.blue().abs_diff(colour.blue()) <= 1);
+            prop_assert!((parsed.alpha().value() - colour.alpha().value()).abs() <= 1. / 255.);
+        }
+    }
+}
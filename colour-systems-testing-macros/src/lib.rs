@@ -1,7 +1,5 @@
 macro_rules! test {
-    ($()) => {
-        
-    };
+    () => {};
 }
 
 test!();